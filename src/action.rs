@@ -2,7 +2,7 @@
 
 use crate::effect::Effect;
 use crate::process::REST_SITE_HEAL_FACTOR;
-use crate::state::GameState;
+use crate::state::{GameState, monster_ids_alive};
 use crate::types::*;
 
 #[derive(Debug, Clone, Copy)]
@@ -13,8 +13,11 @@ pub enum Action {
     SelectMapNode { column: usize },
     SelectCardReward { reward_idx: usize },
     SkipCardReward,
+    SelectRelicReward { reward_idx: usize },
+    SkipRelicReward,
     Rest,
     Upgrade { deck_idx: usize },
+    Recall,
 }
 
 pub fn handle_action(state: &mut GameState, action: Action) -> Vec<Effect> {
@@ -40,12 +43,21 @@ pub fn handle_action(state: &mut GameState, action: Action) -> Vec<Effect> {
         (Fsm::CardReward, Action::SkipCardReward) => {
             handle_card_reward_skip(state)
         }
+        (Fsm::RelicReward, Action::SelectRelicReward { reward_idx }) => {
+            handle_relic_reward_select(state, reward_idx)
+        }
+        (Fsm::RelicReward, Action::SkipRelicReward) => {
+            handle_relic_reward_skip(state)
+        }
         (Fsm::RestSite, Action::Rest) => {
             handle_rest(state)
         }
         (Fsm::RestSite, Action::Upgrade { deck_idx }) => {
             handle_upgrade(state, deck_idx)
         }
+        (Fsm::RestSite, Action::Recall) => {
+            handle_recall(state)
+        }
         _ => panic!("Invalid action {:?} in state {:?}", action, state.fsm),
     }
 }
@@ -54,18 +66,22 @@ fn handle_play_card(state: &mut GameState, hand_idx: usize) -> Vec<Effect> {
     let card_idx = state.hand[hand_idx];
     let card = &state.combat_cards[card_idx];
 
+    assert!(!card.unplayable, "Card {:?} is unplayable", card.name);
+    assert!(card.conditions_met(state), "Card {:?} can't be played now", card.name);
+
     // Energy check
     assert!(
-        card.cost <= state.energy.current,
+        card.cost_met(state.energy.current),
         "Not enough energy: need {}, have {}",
         card.cost, state.energy.current
     );
 
     if card.requires_target() {
-        // If only one monster, auto-target (fast_mode behavior)
-        if state.monsters.len() == 1 {
+        // If only one monster is left, auto-target (fast_mode behavior)
+        let alive: Vec<u8> = monster_ids_alive(state).collect();
+        if let [monster_idx] = alive[..] {
             vec![
-                Effect::TargetSet { monster_idx: 0 },
+                Effect::TargetSet { monster_idx },
                 Effect::CardActiveClear,
                 Effect::CardPlay { card_idx },
                 Effect::TargetClear,
@@ -80,6 +96,11 @@ fn handle_play_card(state: &mut GameState, hand_idx: usize) -> Vec<Effect> {
 
 fn handle_select_monster(state: &mut GameState, monster_idx: u8) -> Vec<Effect> {
     let card_idx = state.card_active.expect("No active card for monster select");
+    assert!(
+        state.monsters[monster_idx as usize].alive,
+        "Monster {} is dead",
+        monster_idx
+    );
     vec![
         Effect::TargetSet { monster_idx },
         Effect::CardActiveClear,
@@ -100,10 +121,9 @@ fn handle_select_map_node(state: &mut GameState, column: usize) -> Vec<Effect> {
     state.effect_queue.pop_front();
 
     // Set map active
-    let y = if state.map.active_y.is_none() {
-        0
-    } else {
-        state.map.active_y.unwrap() + 1
+    let y = match state.map.active_y {
+        Some(y) => y + 1,
+        None => 0,
     };
 
     state.map.active_y = Some(y);
@@ -121,7 +141,7 @@ fn handle_card_reward_select(state: &mut GameState, reward_idx: usize) -> Vec<Ef
 
     vec![
         Effect::CardRewardSelect { reward_idx },
-        Effect::AwaitMapNode,
+        map_next(state),
     ]
 }
 
@@ -131,45 +151,84 @@ fn handle_card_reward_skip(state: &mut GameState) -> Vec<Effect> {
 
     vec![
         Effect::CardRewardClear,
-        Effect::AwaitMapNode,
+        map_next(state),
     ]
 }
 
-fn handle_rest(state: &mut GameState) -> Vec<Effect> {
-    let heal = (REST_SITE_HEAL_FACTOR * state.character.vitals.health_max as f32) as u16;
+fn handle_relic_reward_select(state: &mut GameState, reward_idx: usize) -> Vec<Effect> {
+    assert!(
+        reward_idx < state.relic_rewards.len(),
+        "Relic reward {} out of range ({} offered)",
+        reward_idx,
+        state.relic_rewards.len()
+    );
 
-    let is_last_floor = state.map.active_y == Some(crate::map::MAP_HEIGHT - 1);
+    // Remove AwaitRelicReward from queue
+    state.effect_queue.pop_front();
 
-    let mut effects = vec![
-        Effect::HealthGain { target: ActorId::Character, amount: heal },
-    ];
+    vec![
+        Effect::RelicRewardSelect { reward_idx },
+        relic_reward_next(state),
+    ]
+}
+
+fn handle_relic_reward_skip(state: &mut GameState) -> Vec<Effect> {
+    // Remove AwaitRelicReward from queue
+    state.effect_queue.pop_front();
+
+    let mut effects = vec![Effect::RelicRewardClear];
+
+    // Skipping a treasure chest relic takes the Sapphire Key instead
+    let treasure = state.map.active_room_type() == Some(RoomType::Treasure);
+    if treasure && !state.keys.sapphire && !state.relic_rewards.is_empty() {
+        effects.push(Effect::KeyObtain { key: KeyKind::Sapphire });
+    }
+
+    effects.push(relic_reward_next(state));
+    effects
+}
+
+pub fn relic_reward_next(state: &mut GameState) -> Effect {
+    // The boss chest is the last thing in the act
+    if state.map.is_boss_room() {
+        Effect::ActAdvance
+    } else {
+        map_next(state)
+    }
+}
 
-    if is_last_floor {
-        // Boss fight: go directly to boss room
+/// Leaving a room: the last floor leads straight into the boss room.
+fn map_next(state: &mut GameState) -> Effect {
+    if state.map.is_last_floor() {
         state.map.active_y = Some(state.map.boss_room_y);
         state.map.active_x = Some(0); // boss doesn't have a real column
-        effects.push(Effect::RoomEnter);
+        Effect::RoomEnter
     } else {
-        effects.push(Effect::AwaitMapNode);
+        Effect::AwaitMapNode
     }
+}
 
-    effects
+fn handle_rest(state: &mut GameState) -> Vec<Effect> {
+    let heal = (REST_SITE_HEAL_FACTOR * state.character.vitals.health_max as f32) as u16;
+
+    vec![
+        Effect::HealthGain { target: ActorId::Character, amount: heal },
+        map_next(state),
+    ]
 }
 
 fn handle_upgrade(state: &mut GameState, deck_idx: usize) -> Vec<Effect> {
-    let is_last_floor = state.map.active_y == Some(crate::map::MAP_HEIGHT - 1);
-
-    let mut effects = vec![
+    vec![
         Effect::CardUpgrade { deck_idx },
-    ];
+        map_next(state),
+    ]
+}
 
-    if is_last_floor {
-        state.map.active_y = Some(state.map.boss_room_y);
-        state.map.active_x = Some(0);
-        effects.push(Effect::RoomEnter);
-    } else {
-        effects.push(Effect::AwaitMapNode);
-    }
+fn handle_recall(state: &mut GameState) -> Vec<Effect> {
+    assert!(!state.keys.ruby, "Ruby Key already obtained");
 
-    effects
+    vec![
+        Effect::KeyObtain { key: KeyKind::Ruby },
+        map_next(state),
+    ]
 }
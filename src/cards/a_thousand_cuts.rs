@@ -13,14 +13,19 @@ pub static A_THOUSAND_CUTS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 2,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::ThousandCuts,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static A_THOUSAND_CUTS_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static A_THOUSAND_CUTS_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 2,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::ThousandCuts,
         stacks: 2, // +1 stack
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
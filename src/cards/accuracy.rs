@@ -13,14 +13,19 @@ pub static ACCURACY: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Accuracy,
         stacks: 4,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static ACCURACY_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static ACCURACY_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Accuracy,
         stacks: 6, // +2 stacks
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
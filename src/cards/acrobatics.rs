@@ -12,15 +12,21 @@ pub static ACROBATICS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::CardDraw { count: 3 },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static ACROBATICS_PLUS: Card = Card {
@@ -29,13 +35,19 @@ pub static ACROBATICS_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::CardDraw { count: 4 }, // +1 draw
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -11,13 +11,18 @@ pub static ADRENALINE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::EnergyGain { amount: 1 },
         EffectTemplate::CardDraw { count: 2 },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static ADRENALINE_PLUS: Card = Card {
@@ -26,11 +31,16 @@ pub static ADRENALINE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::EnergyGain { amount: 2 }, // +1 energy gain
         EffectTemplate::CardDraw { count: 2 },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -13,14 +13,19 @@ pub static AFTER_IMAGE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::AfterImage,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static AFTER_IMAGE_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static AFTER_IMAGE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: true, // is innate
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::AfterImage,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -13,18 +13,24 @@ pub static ALL_OUT_ATTACK: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 10,
             target: TargetKind::AllMonsters,
         },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Random,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static ALL_OUT_ATTACK_PLUS: Card = Card {
@@ -33,16 +39,22 @@ pub static ALL_OUT_ATTACK_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 14, // +4 damage
             target: TargetKind::AllMonsters,
         },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Random,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -12,9 +12,12 @@ pub static BACKFLIP: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 5,
@@ -22,6 +25,8 @@ pub static BACKFLIP: Card = Card {
         },
         EffectTemplate::CardDraw { count: 2 },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static BACKFLIP_PLUS: Card = Card {
@@ -30,9 +35,12 @@ pub static BACKFLIP_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 8, // +3 block
@@ -40,4 +48,6 @@ pub static BACKFLIP_PLUS: Card = Card {
         },
         EffectTemplate::CardDraw { count: 2 },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
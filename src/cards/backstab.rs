@@ -12,13 +12,18 @@ pub static BACKSTAB: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: true,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 11,
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static BACKSTAB_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static BACKSTAB_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: true,
     innate: true,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 15, // +4 damage
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
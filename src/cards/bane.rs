@@ -0,0 +1,73 @@
+use crate::cards::Card;
+use crate::effect::Condition;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static BANE: Card = Card {
+    name: CardName::Bane,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 7,
+            target: TargetKind::CardTarget,
+        },
+        // Hits again if the target is poisoned
+        EffectTemplate::If {
+            condition: Condition::TargetHas {
+                kind: ModifierKind::Poison,
+            },
+            then: &[EffectTemplate::DamagePhysical {
+                base: 7,
+                target: TargetKind::CardTarget,
+            }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static BANE_PLUS: Card = Card {
+    name: CardName::Bane,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 10, // +3 damage
+            target: TargetKind::CardTarget,
+        },
+        // Hits again if the target is poisoned
+        EffectTemplate::If {
+            condition: Condition::TargetHas {
+                kind: ModifierKind::Poison,
+            },
+            then: &[EffectTemplate::DamagePhysical {
+                base: 10, // +3 damage
+                target: TargetKind::CardTarget,
+            }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
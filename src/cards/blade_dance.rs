@@ -11,10 +11,15 @@ pub static BLADE_DANCE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::AddShivs { count: 3 }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static BLADE_DANCE_PLUS: Card = Card {
@@ -23,8 +28,13 @@ pub static BLADE_DANCE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::AddShivs { count: 4 }], // +1 shiv
+    end_of_turn: &[],
+    on_draw: &[],
 };
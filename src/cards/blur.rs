@@ -13,9 +13,12 @@ pub static BLUR: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 5,
@@ -27,6 +30,8 @@ pub static BLUR: Card = Card {
             target: TargetKind::Character,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static BLUR_PLUS: Card = Card {
@@ -35,9 +40,12 @@ pub static BLUR_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 8, // +3 block
@@ -49,4 +57,6 @@ pub static BLUR_PLUS: Card = Card {
             target: TargetKind::Character,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
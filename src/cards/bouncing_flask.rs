@@ -0,0 +1,79 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static BOUNCING_FLASK: Card = Card {
+    name: CardName::BouncingFlask,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 2,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static BOUNCING_FLASK_PLUS: Card = Card {
+    name: CardName::BouncingFlask,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 2,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::RandomMonster,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3, // +1 bounce
+            target: TargetKind::RandomMonster,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,47 @@
+use crate::cards::Card;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static BURN: Card = Card {
+    name: CardName::Burn,
+    kind: CardKind::Status,
+    color: CardColor::Colorless,
+    rarity: CardRarity::Special,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[EffectTemplate::DamageDeal {
+        amount: 2,
+        target: TargetKind::Character,
+    }],
+    on_draw: &[],
+};
+// Upgraded (deals more damage at end of turn)
+pub static BURN_PLUS: Card = Card {
+    name: CardName::Burn,
+    kind: CardKind::Status,
+    color: CardColor::Colorless,
+    rarity: CardRarity::Special,
+    cost: 0,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[EffectTemplate::DamageDeal {
+        amount: 4,
+        target: TargetKind::Character,
+    }],
+    on_draw: &[],
+};
@@ -13,14 +13,19 @@ pub static BURST: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Burst,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static BURST_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static BURST_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Burst,
         stacks: 2, // +1 stack
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
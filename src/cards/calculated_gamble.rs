@@ -11,10 +11,15 @@ pub static CALCULATED_GAMBLE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::CalculatedGamble],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static CALCULATED_GAMBLE_PLUS: Card = Card {
@@ -23,8 +28,13 @@ pub static CALCULATED_GAMBLE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: false, // doesn't exhaust
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::CalculatedGamble],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -0,0 +1,50 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static CATALYST: Card = Card {
+    name: CardName::Catalyst,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierMultiply {
+        kind: ModifierKind::Poison,
+        factor: 2,
+        target: TargetKind::CardTarget,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static CATALYST_PLUS: Card = Card {
+    name: CardName::Catalyst,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierMultiply {
+        kind: ModifierKind::Poison,
+        factor: 3, // triple instead of double
+        target: TargetKind::CardTarget,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -12,9 +12,12 @@ pub static CLOAK_AND_DAGGER: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 6,
@@ -22,6 +25,8 @@ pub static CLOAK_AND_DAGGER: Card = Card {
         },
         EffectTemplate::AddShivs { count: 1 },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static CLOAK_AND_DAGGER_PLUS: Card = Card {
@@ -30,9 +35,12 @@ pub static CLOAK_AND_DAGGER_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 6,
@@ -40,4 +48,6 @@ pub static CLOAK_AND_DAGGER_PLUS: Card = Card {
         },
         EffectTemplate::AddShivs { count: 2 }, // +1 shiv
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
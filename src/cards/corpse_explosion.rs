@@ -0,0 +1,64 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static CORPSE_EXPLOSION: Card = Card {
+    name: CardName::CorpseExplosion,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 2,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 6,
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::CorpseExplosion,
+            stacks: 1,
+            target: TargetKind::CardTarget,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static CORPSE_EXPLOSION_PLUS: Card = Card {
+    name: CardName::CorpseExplosion,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 2,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 9, // +3 stacks
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::CorpseExplosion,
+            stacks: 1,
+            target: TargetKind::CardTarget,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
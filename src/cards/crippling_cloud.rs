@@ -0,0 +1,64 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static CRIPPLING_CLOUD: Card = Card {
+    name: CardName::CripplingCloud,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 2,
+    x_cost: false,
+    upgraded: false,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 4,
+            target: TargetKind::AllMonsters,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Weak,
+            stacks: 2,
+            target: TargetKind::AllMonsters,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static CRIPPLING_CLOUD_PLUS: Card = Card {
+    name: CardName::CripplingCloud,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 2,
+    x_cost: false,
+    upgraded: true,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 7, // +3 stacks
+            target: TargetKind::AllMonsters,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Weak,
+            stacks: 2,
+            target: TargetKind::AllMonsters,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
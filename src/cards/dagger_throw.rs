@@ -13,9 +13,12 @@ pub static DAGGER_THROW: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 9,
@@ -23,9 +26,12 @@ pub static DAGGER_THROW: Card = Card {
         },
         EffectTemplate::CardDraw { count: 1 },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DAGGER_THROW_PLUS: Card = Card {
@@ -34,9 +40,12 @@ pub static DAGGER_THROW_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 12, // +3 damage
@@ -44,7 +53,10 @@ pub static DAGGER_THROW_PLUS: Card = Card {
         },
         EffectTemplate::CardDraw { count: 1 },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -12,9 +12,12 @@ pub static DASH: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 2,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 10,
@@ -25,6 +28,8 @@ pub static DASH: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DASH_PLUS: Card = Card {
@@ -33,9 +38,12 @@ pub static DASH_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 2,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 13, // +3 damage
@@ -46,4 +54,6 @@ pub static DASH_PLUS: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
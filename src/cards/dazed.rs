@@ -0,0 +1,22 @@
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static DAZED: Card = Card {
+    name: CardName::Dazed,
+    kind: CardKind::Status,
+    color: CardColor::Colorless,
+    rarity: CardRarity::Special,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: true,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[],
+    on_draw: &[],
+};
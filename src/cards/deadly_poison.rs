@@ -0,0 +1,50 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static DEADLY_POISON: Card = Card {
+    name: CardName::DeadlyPoison,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::Poison,
+        stacks: 5,
+        target: TargetKind::CardTarget,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static DEADLY_POISON_PLUS: Card = Card {
+    name: CardName::DeadlyPoison,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::Poison,
+        stacks: 7, // +2 stacks
+        target: TargetKind::CardTarget,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -12,13 +12,18 @@ pub static DEFEND: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::BlockGain {
         amount: 5,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DEFEND_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static DEFEND_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::BlockGain {
         amount: 8, // +3 block
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -12,13 +12,18 @@ pub static DEFLECT: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::BlockGain {
         amount: 4,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DEFLECT_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static DEFLECT_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::BlockGain {
         amount: 7, // +3 block
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
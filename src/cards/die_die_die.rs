@@ -12,13 +12,18 @@ pub static DIE_DIE_DIE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 13,
         target: TargetKind::AllMonsters,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DIE_DIE_DIE_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static DIE_DIE_DIE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 17, // + 4 damage
         target: TargetKind::AllMonsters,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -13,9 +13,12 @@ pub static DODGE_AND_ROLL: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 4,
@@ -27,6 +30,8 @@ pub static DODGE_AND_ROLL: Card = Card {
             target: TargetKind::Character,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static DODGE_AND_ROLL_PLUS: Card = Card {
@@ -35,9 +40,12 @@ pub static DODGE_AND_ROLL_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 6, // +2 block
@@ -49,4 +57,6 @@ pub static DODGE_AND_ROLL_PLUS: Card = Card {
             target: TargetKind::Character,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -0,0 +1,73 @@
+use crate::cards::Card;
+use crate::effect::CountKind;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static DOPPELGANGER: Card = Card {
+    name: CardName::Doppelganger,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 0,
+    x_cost: true,
+    upgraded: false,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::Scaled {
+        count: CountKind::XCost,
+        bonus: 0,
+        each: &[
+            EffectTemplate::ModifierGain {
+                kind: ModifierKind::NextTurnDraw,
+                stacks: 1,
+                target: TargetKind::Character,
+            },
+            EffectTemplate::ModifierGain {
+                kind: ModifierKind::NextTurnEnergy,
+                stacks: 1,
+                target: TargetKind::Character,
+            },
+        ],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static DOPPELGANGER_PLUS: Card = Card {
+    name: CardName::Doppelganger,
+    kind: CardKind::Skill,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 0,
+    x_cost: true,
+    upgraded: true,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::Scaled {
+        count: CountKind::XCost,
+        bonus: 1, // X+1
+        each: &[
+            EffectTemplate::ModifierGain {
+                kind: ModifierKind::NextTurnDraw,
+                stacks: 1,
+                target: TargetKind::Character,
+            },
+            EffectTemplate::ModifierGain {
+                kind: ModifierKind::NextTurnEnergy,
+                stacks: 1,
+                target: TargetKind::Character,
+            },
+        ],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
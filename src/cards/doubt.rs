@@ -0,0 +1,29 @@
+use crate::cards::Card;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static DOUBT: Card = Card {
+    name: CardName::Doubt,
+    kind: CardKind::Curse,
+    color: CardColor::Curse,
+    rarity: CardRarity::Curse,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::Weak,
+        stacks: 1,
+        target: TargetKind::Character,
+    }],
+    on_draw: &[],
+};
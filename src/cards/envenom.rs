@@ -0,0 +1,50 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static ENVENOM: Card = Card {
+    name: CardName::Envenom,
+    kind: CardKind::Power,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 2,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::Envenom,
+        stacks: 1,
+        target: TargetKind::Character,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static ENVENOM_PLUS: Card = Card {
+    name: CardName::Envenom,
+    kind: CardKind::Power,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 1, // -1 cost
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::Envenom,
+        stacks: 1,
+        target: TargetKind::Character,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,54 @@
+use crate::cards::Card;
+use crate::effect::CountKind;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static FINISHER: Card = Card {
+    name: CardName::Finisher,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ForEach {
+        count: CountKind::AttacksPlayedThisTurn,
+        each: &[EffectTemplate::DamagePhysical {
+            base: 6,
+            target: TargetKind::CardTarget,
+        }],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static FINISHER_PLUS: Card = Card {
+    name: CardName::Finisher,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ForEach {
+        count: CountKind::AttacksPlayedThisTurn,
+        each: &[EffectTemplate::DamagePhysical {
+            base: 8, // +2 damage
+            target: TargetKind::CardTarget,
+        }],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,58 @@
+use crate::cards::Card;
+use crate::effect::Condition;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static GRAND_FINALE: Card = Card {
+    name: CardName::GrandFinale,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::PlayableIf {
+            condition: Condition::DrawPileEmpty,
+        },
+        EffectTemplate::DamagePhysical {
+            base: 50,
+            target: TargetKind::AllMonsters,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static GRAND_FINALE_PLUS: Card = Card {
+    name: CardName::GrandFinale,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Rare,
+    cost: 0,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::PlayableIf {
+            condition: Condition::DrawPileEmpty,
+        },
+        EffectTemplate::DamagePhysical {
+            base: 60, // +10 damage
+            target: TargetKind::AllMonsters,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,65 @@
+use crate::cards::Card;
+use crate::effect::Condition;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static HEEL_HOOK: Card = Card {
+    name: CardName::HeelHook,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 5,
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::If {
+            condition: Condition::TargetHas {
+                kind: ModifierKind::Weak,
+            },
+            then: &[EffectTemplate::EnergyGain { amount: 1 }, EffectTemplate::CardDraw { count: 1 }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static HEEL_HOOK_PLUS: Card = Card {
+    name: CardName::HeelHook,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 8, // +3 damage
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::If {
+            condition: Condition::TargetHas {
+                kind: ModifierKind::Weak,
+            },
+            then: &[EffectTemplate::EnergyGain { amount: 1 }, EffectTemplate::CardDraw { count: 1 }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -13,14 +13,19 @@ pub static INFINITE_BLADES: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::InfiniteBlades,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static INFINITE_BLADES_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static INFINITE_BLADES_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: true, // is innate
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::InfiniteBlades,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
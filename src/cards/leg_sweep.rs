@@ -13,9 +13,12 @@ pub static LEG_SWEEP: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 2,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 11,
@@ -27,6 +30,8 @@ pub static LEG_SWEEP: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static LEG_SWEEP_PLUS: Card = Card {
@@ -35,9 +40,12 @@ pub static LEG_SWEEP_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 2,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 14, // +3 block
@@ -49,4 +57,6 @@ pub static LEG_SWEEP_PLUS: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
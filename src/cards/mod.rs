@@ -4,33 +4,66 @@ pub mod acrobatics;
 pub mod adrenaline;
 pub mod after_image;
 pub mod all_out_attack;
+pub mod ascenders_bane;
 pub mod backflip;
 pub mod backstab;
+pub mod bane;
 pub mod blade_dance;
 pub mod blur;
+pub mod bouncing_flask;
+pub mod burn;
 pub mod burst;
 pub mod calculated_gamble;
+pub mod catalyst;
 pub mod cloak_and_dagger;
+pub mod clumsy;
+pub mod corpse_explosion;
+pub mod crippling_cloud;
 pub mod dagger_throw;
 pub mod dash;
+pub mod dazed;
+pub mod deadly_poison;
 pub mod defend;
 pub mod deflect;
 pub mod die_die_die;
 pub mod dodge_and_roll;
+pub mod doppelganger;
+pub mod doubt;
+pub mod envenom;
+pub mod finisher;
+pub mod flechettes;
 pub mod flying_knee;
 pub mod footwork;
+pub mod grand_finale;
+pub mod heel_hook;
 pub mod infinite_blades;
+pub mod injury;
 pub mod leg_sweep;
+pub mod malaise;
 pub mod neutralize;
+pub mod noxious_fumes;
 pub mod outmaneuver;
+pub mod parasite;
 pub mod phantasmal_killer;
+pub mod poisoned_stab;
+pub mod regret;
 pub mod shiv;
+pub mod skewer;
+pub mod slimed;
+pub mod sneaky_strike;
 pub mod strike;
 pub mod survivor;
 pub mod terror;
+pub mod void;
+pub mod wound;
 
+use crate::effect::Condition;
 use crate::effect::EffectTemplate;
 use crate::effect::TargetKind;
+use crate::process::condition_holds;
+use crate::registry;
+use crate::state::GameState;
+use crate::types::ActorId;
 use crate::types::CardColor;
 use crate::types::CardKind;
 use crate::types::CardName;
@@ -43,31 +76,32 @@ pub struct Card {
     pub color: CardColor,
     pub rarity: CardRarity,
     pub cost: u8,
+    pub x_cost: bool, // spends all energy instead of `cost`
     pub upgraded: bool,
     pub exhaust: bool,
     pub innate: bool,
+    pub ethereal: bool,
+    pub unplayable: bool,
     pub effects: &'static [EffectTemplate],
+    pub end_of_turn: &'static [EffectTemplate], // while in hand at the end of the turn
+    pub on_draw: &'static [EffectTemplate],
 }
 
 impl Card {
     pub fn requires_target(&self) -> bool {
-        self.effects.iter().any(|e| {
-            matches!(
-                e,
-                EffectTemplate::DamagePhysical {
-                    target: TargetKind::CardTarget,
-                    ..
-                } | EffectTemplate::BlockGain {
-                    target: TargetKind::CardTarget,
-                    ..
-                } | EffectTemplate::ModifierGain {
-                    target: TargetKind::CardTarget,
-                    ..
-                } | EffectTemplate::ModifierRemove {
-                    target: TargetKind::CardTarget,
-                    ..
-                }
-            )
+        templates_require_target(self.effects)
+    }
+
+    pub fn cost_met(&self, energy: u8) -> bool {
+        self.x_cost || self.cost <= energy
+    }
+
+    /// Whether the card's `PlayableIf` conditions hold; cost and `unplayable`
+    /// are checked separately.
+    pub fn conditions_met(&self, state: &GameState) -> bool {
+        self.effects.iter().all(|e| match *e {
+            EffectTemplate::PlayableIf { condition } => condition_holds(state, condition, ActorId::Character),
+            _ => true,
         })
     }
 
@@ -77,14 +111,34 @@ impl Card {
             matches!(
                 e,
                 EffectTemplate::CardDiscard {
-                    selection: SelectionKind::Input
+                    selection: SelectionKind::Input,
+                    ..
                 }
             )
         })
     }
 }
 
+fn templates_require_target(effects: &[EffectTemplate]) -> bool {
+    effects.iter().any(|e| match e {
+        EffectTemplate::DamagePhysical { target, .. }
+        | EffectTemplate::BlockGain { target, .. }
+        | EffectTemplate::ModifierGain { target, .. }
+        | EffectTemplate::ModifierRemove { target, .. }
+        | EffectTemplate::ModifierMultiply { target, .. } => *target == TargetKind::CardTarget,
+        EffectTemplate::If { condition, then } => {
+            matches!(condition, Condition::TargetHas { .. }) || templates_require_target(then)
+        }
+        EffectTemplate::ForEach { each, .. } | EffectTemplate::Scaled { each, .. } => templates_require_target(each),
+        _ => false,
+    })
+}
+
 pub fn get_card(name: CardName, upgraded: bool) -> Card {
+    if let Some(card) = registry::card_loaded(name, upgraded) {
+        return card;
+    }
+
     match (name, upgraded) {
         (CardName::AThousandCuts, false) => a_thousand_cuts::A_THOUSAND_CUTS,
         (CardName::AThousandCuts, true) => a_thousand_cuts::A_THOUSAND_CUTS_PLUS,
@@ -98,24 +152,41 @@ pub fn get_card(name: CardName, upgraded: bool) -> Card {
         (CardName::AfterImage, true) => after_image::AFTER_IMAGE_PLUS,
         (CardName::AllOutAttack, false) => all_out_attack::ALL_OUT_ATTACK,
         (CardName::AllOutAttack, true) => all_out_attack::ALL_OUT_ATTACK_PLUS,
+        (CardName::AscendersBane, _) => ascenders_bane::ASCENDERS_BANE,
         (CardName::Backflip, false) => backflip::BACKFLIP,
         (CardName::Backflip, true) => backflip::BACKFLIP_PLUS,
         (CardName::Backstab, false) => backstab::BACKSTAB,
         (CardName::Backstab, true) => backstab::BACKSTAB_PLUS,
+        (CardName::Bane, false) => bane::BANE,
+        (CardName::Bane, true) => bane::BANE_PLUS,
         (CardName::BladeDance, false) => blade_dance::BLADE_DANCE,
         (CardName::BladeDance, true) => blade_dance::BLADE_DANCE_PLUS,
         (CardName::Blur, false) => blur::BLUR,
         (CardName::Blur, true) => blur::BLUR_PLUS,
+        (CardName::BouncingFlask, false) => bouncing_flask::BOUNCING_FLASK,
+        (CardName::BouncingFlask, true) => bouncing_flask::BOUNCING_FLASK_PLUS,
+        (CardName::Burn, false) => burn::BURN,
+        (CardName::Burn, true) => burn::BURN_PLUS,
         (CardName::Burst, false) => burst::BURST,
         (CardName::Burst, true) => burst::BURST_PLUS,
         (CardName::CalculatedGamble, false) => calculated_gamble::CALCULATED_GAMBLE,
         (CardName::CalculatedGamble, true) => calculated_gamble::CALCULATED_GAMBLE_PLUS,
+        (CardName::Catalyst, false) => catalyst::CATALYST,
+        (CardName::Catalyst, true) => catalyst::CATALYST_PLUS,
         (CardName::CloakAndDagger, false) => cloak_and_dagger::CLOAK_AND_DAGGER,
         (CardName::CloakAndDagger, true) => cloak_and_dagger::CLOAK_AND_DAGGER_PLUS,
+        (CardName::Clumsy, _) => clumsy::CLUMSY,
+        (CardName::CorpseExplosion, false) => corpse_explosion::CORPSE_EXPLOSION,
+        (CardName::CorpseExplosion, true) => corpse_explosion::CORPSE_EXPLOSION_PLUS,
+        (CardName::CripplingCloud, false) => crippling_cloud::CRIPPLING_CLOUD,
+        (CardName::CripplingCloud, true) => crippling_cloud::CRIPPLING_CLOUD_PLUS,
         (CardName::DaggerThrow, false) => dagger_throw::DAGGER_THROW,
         (CardName::DaggerThrow, true) => dagger_throw::DAGGER_THROW_PLUS,
         (CardName::Dash, false) => dash::DASH,
         (CardName::Dash, true) => dash::DASH_PLUS,
+        (CardName::Dazed, _) => dazed::DAZED,
+        (CardName::DeadlyPoison, false) => deadly_poison::DEADLY_POISON,
+        (CardName::DeadlyPoison, true) => deadly_poison::DEADLY_POISON_PLUS,
         (CardName::Defend, false) => defend::DEFEND,
         (CardName::Defend, true) => defend::DEFEND_PLUS,
         (CardName::Deflect, false) => deflect::DEFLECT,
@@ -124,28 +195,63 @@ pub fn get_card(name: CardName, upgraded: bool) -> Card {
         (CardName::DieDieDie, true) => die_die_die::DIE_DIE_DIE_PLUS,
         (CardName::DodgeAndRoll, false) => dodge_and_roll::DODGE_AND_ROLL,
         (CardName::DodgeAndRoll, true) => dodge_and_roll::DODGE_AND_ROLL_PLUS,
+        (CardName::Doppelganger, false) => doppelganger::DOPPELGANGER,
+        (CardName::Doppelganger, true) => doppelganger::DOPPELGANGER_PLUS,
+        (CardName::Doubt, _) => doubt::DOUBT,
+        (CardName::Envenom, false) => envenom::ENVENOM,
+        (CardName::Envenom, true) => envenom::ENVENOM_PLUS,
+        (CardName::Finisher, false) => finisher::FINISHER,
+        (CardName::Finisher, true) => finisher::FINISHER_PLUS,
+        (CardName::Flechettes, false) => flechettes::FLECHETTES,
+        (CardName::Flechettes, true) => flechettes::FLECHETTES_PLUS,
         (CardName::FlyingKnee, false) => flying_knee::FLYING_KNEE,
         (CardName::FlyingKnee, true) => flying_knee::FLYING_KNEE_PLUS,
         (CardName::Footwork, false) => footwork::FOOTWORK,
         (CardName::Footwork, true) => footwork::FOOTWORK_PLUS,
+        (CardName::GrandFinale, false) => grand_finale::GRAND_FINALE,
+        (CardName::GrandFinale, true) => grand_finale::GRAND_FINALE_PLUS,
+        (CardName::HeelHook, false) => heel_hook::HEEL_HOOK,
+        (CardName::HeelHook, true) => heel_hook::HEEL_HOOK_PLUS,
         (CardName::InfiniteBlades, false) => infinite_blades::INFINITE_BLADES,
         (CardName::InfiniteBlades, true) => infinite_blades::INFINITE_BLADES_PLUS,
+        (CardName::Injury, _) => injury::INJURY,
         (CardName::LegSweep, false) => leg_sweep::LEG_SWEEP,
         (CardName::LegSweep, true) => leg_sweep::LEG_SWEEP_PLUS,
+        (CardName::Malaise, false) => malaise::MALAISE,
+        (CardName::Malaise, true) => malaise::MALAISE_PLUS,
         (CardName::Neutralize, false) => neutralize::NEUTRALIZE,
         (CardName::Neutralize, true) => neutralize::NEUTRALIZE_PLUS,
+        (CardName::NoxiousFumes, false) => noxious_fumes::NOXIOUS_FUMES,
+        (CardName::NoxiousFumes, true) => noxious_fumes::NOXIOUS_FUMES_PLUS,
         (CardName::Outmaneuver, false) => outmaneuver::OUTMANEUVER,
         (CardName::Outmaneuver, true) => outmaneuver::OUTMANEUVER_PLUS,
+        (CardName::Parasite, _) => parasite::PARASITE,
         (CardName::PhantasmalKiller, false) => phantasmal_killer::PHANTASMAL_KILLER,
         (CardName::PhantasmalKiller, true) => phantasmal_killer::PHANTASMAL_KILLER_PLUS,
+        (CardName::PoisonedStab, false) => poisoned_stab::POISONED_STAB,
+        (CardName::PoisonedStab, true) => poisoned_stab::POISONED_STAB_PLUS,
+        (CardName::Regret, _) => regret::REGRET,
         (CardName::Shiv, false) => shiv::SHIV,
         (CardName::Shiv, true) => shiv::SHIV_PLUS,
+        (CardName::Skewer, false) => skewer::SKEWER,
+        (CardName::Skewer, true) => skewer::SKEWER_PLUS,
+        (CardName::Slimed, _) => slimed::SLIMED,
+        (CardName::SneakyStrike, false) => sneaky_strike::SNEAKY_STRIKE,
+        (CardName::SneakyStrike, true) => sneaky_strike::SNEAKY_STRIKE_PLUS,
         (CardName::Strike, false) => strike::STRIKE,
         (CardName::Strike, true) => strike::STRIKE_PLUS,
         (CardName::Survivor, false) => survivor::SURVIVOR,
         (CardName::Survivor, true) => survivor::SURVIVOR_PLUS,
         (CardName::Terror, false) => terror::TERROR,
         (CardName::Terror, true) => terror::TERROR_PLUS,
+        (CardName::Void, _) => void::VOID,
+        (CardName::Wound, _) => wound::WOUND,
+        // Registered cards without an upgraded definition upgrade to themselves
+        (_, true) => Card {
+            upgraded: true,
+            ..get_card(name, false)
+        },
+        _ => panic!("No definition loaded for card {name:?}"),
     }
 }
 
@@ -153,13 +259,17 @@ pub fn get_card(name: CardName, upgraded: bool) -> Card {
 pub const REWARD_POOL_COMMON: &[CardName] = &[
     CardName::Acrobatics,
     CardName::Backflip,
+    CardName::Bane,
     CardName::BladeDance,
     CardName::CloakAndDagger,
     CardName::DaggerThrow,
+    CardName::DeadlyPoison,
     CardName::Deflect,
     CardName::DodgeAndRoll,
     CardName::FlyingKnee,
     CardName::Outmaneuver,
+    CardName::PoisonedStab,
+    CardName::SneakyStrike,
 ];
 
 pub const REWARD_POOL_UNCOMMON: &[CardName] = &[
@@ -167,11 +277,19 @@ pub const REWARD_POOL_UNCOMMON: &[CardName] = &[
     CardName::AllOutAttack,
     CardName::Backstab,
     CardName::Blur,
+    CardName::BouncingFlask,
     CardName::CalculatedGamble,
+    CardName::Catalyst,
+    CardName::CripplingCloud,
     CardName::Dash,
+    CardName::Finisher,
+    CardName::Flechettes,
     CardName::Footwork,
+    CardName::HeelHook,
     CardName::InfiniteBlades,
     CardName::LegSweep,
+    CardName::NoxiousFumes,
+    CardName::Skewer,
     CardName::Terror,
 ];
 
@@ -180,6 +298,26 @@ pub const REWARD_POOL_RARE: &[CardName] = &[
     CardName::Adrenaline,
     CardName::AfterImage,
     CardName::Burst,
+    CardName::CorpseExplosion,
     CardName::DieDieDie,
+    CardName::Doppelganger,
+    CardName::Envenom,
+    CardName::GrandFinale,
+    CardName::Malaise,
     CardName::PhantasmalKiller,
 ];
+
+/// A reward rarity's built-in pool followed by the registered Green cards of that rarity.
+pub fn reward_pool(rarity: CardRarity) -> Vec<CardName> {
+    let builtin = match rarity {
+        CardRarity::Common => REWARD_POOL_COMMON,
+        CardRarity::Uncommon => REWARD_POOL_UNCOMMON,
+        CardRarity::Rare => REWARD_POOL_RARE,
+        _ => &[],
+    };
+    let registered = registry::cards_registered()
+        .into_iter()
+        .filter(|c| c.color == CardColor::Green && c.rarity == rarity)
+        .map(|c| c.name);
+    builtin.iter().copied().chain(registered).collect()
+}
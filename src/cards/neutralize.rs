@@ -13,9 +13,12 @@ pub static NEUTRALIZE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 3,
@@ -27,6 +30,8 @@ pub static NEUTRALIZE: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static NEUTRALIZE_PLUS: Card = Card {
@@ -35,9 +40,12 @@ pub static NEUTRALIZE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::DamagePhysical {
             base: 4, // +1 damage
@@ -49,4 +57,6 @@ pub static NEUTRALIZE_PLUS: Card = Card {
             target: TargetKind::CardTarget,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -0,0 +1,50 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static NOXIOUS_FUMES: Card = Card {
+    name: CardName::NoxiousFumes,
+    kind: CardKind::Power,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::NoxiousFumes,
+        stacks: 2,
+        target: TargetKind::Character,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static NOXIOUS_FUMES_PLUS: Card = Card {
+    name: CardName::NoxiousFumes,
+    kind: CardKind::Power,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ModifierGain {
+        kind: ModifierKind::NoxiousFumes,
+        stacks: 3, // +1 stack
+        target: TargetKind::Character,
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -13,14 +13,19 @@ pub static OUTMANEUVER: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::NextTurnEnergy,
         stacks: 2,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static OUTMANEUVER_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static OUTMANEUVER_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Common,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::NextTurnEnergy,
         stacks: 3, // +1 next-turn-energy
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -0,0 +1,22 @@
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static PARASITE: Card = Card {
+    name: CardName::Parasite,
+    kind: CardKind::Curse,
+    color: CardColor::Curse,
+    rarity: CardRarity::Curse,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -13,14 +13,19 @@ pub static PHANTASMAL_KILLER: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Phantasmal,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static PHANTASMAL_KILLER_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static PHANTASMAL_KILLER_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Rare,
     cost: 0, // -1 cost
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Phantasmal,
         stacks: 1,
         target: TargetKind::Character,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
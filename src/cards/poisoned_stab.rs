@@ -0,0 +1,62 @@
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::modifier::ModifierKind;
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static POISONED_STAB: Card = Card {
+    name: CardName::PoisonedStab,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 6,
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 3,
+            target: TargetKind::CardTarget,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static POISONED_STAB_PLUS: Card = Card {
+    name: CardName::PoisonedStab,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 1,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 8, // +2 damage
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::ModifierGain {
+            kind: ModifierKind::Poison,
+            stacks: 4, // +1 stack
+            target: TargetKind::CardTarget,
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,32 @@
+use crate::cards::Card;
+use crate::effect::CountKind;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static REGRET: Card = Card {
+    name: CardName::Regret,
+    kind: CardKind::Curse,
+    color: CardColor::Curse,
+    rarity: CardRarity::Curse,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[EffectTemplate::Scaled {
+        count: CountKind::CardsInHand,
+        bonus: 0,
+        each: &[EffectTemplate::HealthLoss {
+            amount: 1,
+            target: TargetKind::Character,
+        }],
+    }],
+    on_draw: &[],
+};
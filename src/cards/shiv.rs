@@ -12,13 +12,18 @@ pub static SHIV: Card = Card {
     color: CardColor::Colorless,
     rarity: CardRarity::Special,
     cost: 0,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 4,
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static SHIV_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static SHIV_PLUS: Card = Card {
     color: CardColor::Colorless,
     rarity: CardRarity::Special,
     cost: 0,
+    x_cost: false,
     upgraded: true,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 6, // +2 damage
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
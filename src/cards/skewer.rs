@@ -0,0 +1,54 @@
+use crate::cards::Card;
+use crate::effect::CountKind;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static SKEWER: Card = Card {
+    name: CardName::Skewer,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 0,
+    x_cost: true,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ForEach {
+        count: CountKind::XCost,
+        each: &[EffectTemplate::DamagePhysical {
+            base: 7,
+            target: TargetKind::CardTarget,
+        }],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static SKEWER_PLUS: Card = Card {
+    name: CardName::Skewer,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Uncommon,
+    cost: 0,
+    x_cost: true,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[EffectTemplate::ForEach {
+        count: CountKind::XCost,
+        each: &[EffectTemplate::DamagePhysical {
+            base: 10, // +3 damage
+            target: TargetKind::CardTarget,
+        }],
+    }],
+    end_of_turn: &[],
+    on_draw: &[],
+};
@@ -0,0 +1,22 @@
+use crate::cards::Card;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static SLIMED: Card = Card {
+    name: CardName::Slimed,
+    kind: CardKind::Status,
+    color: CardColor::Colorless,
+    rarity: CardRarity::Special,
+    cost: 1,
+    x_cost: false,
+    upgraded: false,
+    exhaust: true,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[],
+    end_of_turn: &[],
+    on_draw: &[],
+};
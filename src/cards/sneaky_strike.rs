@@ -0,0 +1,60 @@
+use crate::cards::Card;
+use crate::effect::Condition;
+use crate::effect::EffectTemplate;
+use crate::effect::TargetKind;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static SNEAKY_STRIKE: Card = Card {
+    name: CardName::SneakyStrike,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 2,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 12,
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::If {
+            condition: Condition::DiscardedThisTurn,
+            then: &[EffectTemplate::EnergyGain { amount: 2 }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
+// Upgraded
+pub static SNEAKY_STRIKE_PLUS: Card = Card {
+    name: CardName::SneakyStrike,
+    kind: CardKind::Attack,
+    color: CardColor::Green,
+    rarity: CardRarity::Common,
+    cost: 2,
+    x_cost: false,
+    upgraded: true,
+    exhaust: false,
+    innate: false,
+    ethereal: false,
+    unplayable: false,
+    effects: &[
+        EffectTemplate::DamagePhysical {
+            base: 16, // +4 damage
+            target: TargetKind::CardTarget,
+        },
+        EffectTemplate::If {
+            condition: Condition::DiscardedThisTurn,
+            then: &[EffectTemplate::EnergyGain { amount: 2 }],
+        },
+    ],
+    end_of_turn: &[],
+    on_draw: &[],
+};
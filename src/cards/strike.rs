@@ -12,13 +12,18 @@ pub static STRIKE: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 6,
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static STRIKE_PLUS: Card = Card {
@@ -27,11 +32,16 @@ pub static STRIKE_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::DamagePhysical {
         base: 9, // +3 damage
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
@@ -13,18 +13,24 @@ pub static SURVIVOR: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 8,
             target: TargetKind::Character,
         },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static SURVIVOR_PLUS: Card = Card {
@@ -33,16 +39,22 @@ pub static SURVIVOR_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Basic,
     cost: 1,
+    x_cost: false,
     upgraded: true,
     exhaust: false,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[
         EffectTemplate::BlockGain {
             amount: 11, // +3 block
             target: TargetKind::Character,
         },
         EffectTemplate::CardDiscard {
+            count: 1,
             selection: SelectionKind::Input,
         },
     ],
+    end_of_turn: &[],
+    on_draw: &[],
 };
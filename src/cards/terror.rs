@@ -13,14 +13,19 @@ pub static TERROR: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 1,
+    x_cost: false,
     upgraded: false,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Vulnerable,
         stacks: 99,
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
 // Upgraded
 pub static TERROR_PLUS: Card = Card {
@@ -29,12 +34,17 @@ pub static TERROR_PLUS: Card = Card {
     color: CardColor::Green,
     rarity: CardRarity::Uncommon,
     cost: 0, // -1 cost
+    x_cost: false,
     upgraded: true,
     exhaust: true,
     innate: false,
+    ethereal: false,
+    unplayable: false,
     effects: &[EffectTemplate::ModifierGain {
         kind: ModifierKind::Vulnerable,
         stacks: 99,
         target: TargetKind::CardTarget,
     }],
+    end_of_turn: &[],
+    on_draw: &[],
 };
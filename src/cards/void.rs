@@ -0,0 +1,23 @@
+use crate::cards::Card;
+use crate::effect::EffectTemplate;
+use crate::types::CardColor;
+use crate::types::CardKind;
+use crate::types::CardName;
+use crate::types::CardRarity;
+
+pub static VOID: Card = Card {
+    name: CardName::Void,
+    kind: CardKind::Status,
+    color: CardColor::Colorless,
+    rarity: CardRarity::Special,
+    cost: 0,
+    x_cost: false,
+    upgraded: false,
+    exhaust: false,
+    innate: false,
+    ethereal: true,
+    unplayable: true,
+    effects: &[],
+    end_of_turn: &[],
+    on_draw: &[EffectTemplate::EnergyLoss { amount: 1 }],
+};
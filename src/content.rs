@@ -0,0 +1,714 @@
+// Content loaded at runtime: Python parses the TOML/JSON files and hands over
+// plain dicts, which are turned into cards, monster definitions, modifiers and
+// encounters and stored in the registry. Balance variants and new content need
+// no rebuild.
+
+use std::fmt::Debug;
+
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::cards::{Card, get_card};
+use crate::effect::{Condition, CountKind, EffectTemplate, PileKind, SelectionKind, TargetKind};
+use crate::encounter::{Encounter, EncounterPool, EncounterSlot};
+use crate::modifier::{HOOKS_NONE, Modifier, ModifierKind, modifier_def};
+use crate::monster::{MonsterDef, MoveCondition, MoveDef, MoveRoll, MoveRule, monster_def};
+use crate::registry;
+use crate::state::{Intent, IntentKind};
+use crate::types::{CardColor, CardKind, CardName, CardRarity, MonsterKind, MonsterName};
+
+const TARGET_KINDS: [TargetKind; 7] = [
+    TargetKind::CardTarget,
+    TargetKind::Character,
+    TargetKind::AllMonsters,
+    TargetKind::RandomMonster,
+    TargetKind::AllAllies,
+    TargetKind::RandomAlly,
+    TargetKind::Source,
+];
+const SELECTION_KINDS: [SelectionKind; 2] = [SelectionKind::Input, SelectionKind::Random];
+const PILE_KINDS: [PileKind; 2] = [PileKind::Draw, PileKind::Discard];
+const COUNT_KINDS: [CountKind; 4] = [
+    CountKind::AttacksPlayedThisTurn,
+    CountKind::SkillsInHand,
+    CountKind::CardsInHand,
+    CountKind::XCost,
+];
+const CARD_KINDS: [CardKind; 5] = [
+    CardKind::Attack,
+    CardKind::Curse,
+    CardKind::Power,
+    CardKind::Skill,
+    CardKind::Status,
+];
+const CARD_COLORS: [CardColor; 3] = [CardColor::Colorless, CardColor::Curse, CardColor::Green];
+const MONSTER_KINDS: [MonsterKind; 3] = [MonsterKind::Normal, MonsterKind::Elite, MonsterKind::Boss];
+const INTENT_KINDS: [IntentKind; 15] = [
+    IntentKind::Attack,
+    IntentKind::AttackBuff,
+    IntentKind::AttackDebuff,
+    IntentKind::AttackDefend,
+    IntentKind::Buff,
+    IntentKind::Debuff,
+    IntentKind::StrongDebuff,
+    IntentKind::Defend,
+    IntentKind::DefendBuff,
+    IntentKind::DefendDebuff,
+    IntentKind::Escape,
+    IntentKind::Magic,
+    IntentKind::Sleep,
+    IntentKind::Stun,
+    IntentKind::Unknown,
+];
+const ENCOUNTER_POOLS: [EncounterPool; 4] = [
+    EncounterPool::Weak,
+    EncounterPool::Strong,
+    EncounterPool::Elite,
+    EncounterPool::Boss,
+];
+const CARD_RARITIES: [CardRarity; 6] = [
+    CardRarity::Basic,
+    CardRarity::Common,
+    CardRarity::Uncommon,
+    CardRarity::Rare,
+    CardRarity::Special,
+    CardRarity::Curse,
+];
+
+// ---------------------------------------------------------------------------
+// Cards
+// ---------------------------------------------------------------------------
+
+/// Each dict names a card ("name", optional "upgraded") and may set any other
+/// `Card` field; the rest keep their current values. A new name registers a
+/// new card, which must give "kind", "rarity", "cost" and "effects"; Green
+/// ones join the card rewards. Naming a built-in card is an error unless
+/// `override_builtin` is set. Only cards created after loading (new games,
+/// rewards, generated cards) see the change.
+///
+/// {"name": str, "upgraded": bool, "kind": CardKind, "color": CardColor,
+///  "rarity": CardRarity, "cost": int, "x_cost": bool, "exhaust": bool,
+///  "innate": bool, "ethereal": bool, "unplayable": bool,
+///  "effects": [{"type": EffectTemplate variant, <field>: <value>}],
+///  "end_of_turn": [...], "on_draw": [...]}
+///
+/// Enums are given by their variant names, see `parse_effect` for effects.
+pub fn cards_load(defs: &[Bound<'_, PyDict>], override_builtin: bool) -> PyResult<()> {
+    // Parse everything first so a bad entry leaves the database untouched
+    let cards = defs
+        .iter()
+        .map(|d| parse_card(d, override_builtin))
+        .collect::<PyResult<Vec<_>>>()?;
+    registry::cards_insert(cards);
+    Ok(())
+}
+
+/// Reads a card database: a TOML file of `[[cards]]` tables or a JSON object
+/// with a "cards" list, each entry as in `cards_load`. For example:
+///
+/// [[cards]]
+/// name = "Bash"
+/// kind = "Attack"
+/// rarity = "Common"
+/// cost = 2
+/// effects = [{ type = "DamagePhysical", base = 8, target = "CardTarget" },
+///            { type = "ModifierGain", kind = "Vulnerable", stacks = 2, target = "CardTarget" }]
+pub fn cards_load_file(py: Python<'_>, path: &str, override_builtin: bool) -> PyResult<()> {
+    let text = std::fs::read_to_string(path).map_err(|e| PyOSError::new_err(format!("{path}: {e}")))?;
+    // Python ships both parsers, so the extension needs no TOML/JSON crates
+    let parser = if path.ends_with(".json") { "json" } else { "tomllib" };
+    let data = py.import(parser)?.call_method1("loads", (text,))?;
+    let cards = data
+        .get_item("cards")
+        .map_err(|_| PyValueError::new_err(format!("{path}: missing field: cards")))?
+        .extract::<Vec<Bound<'_, PyDict>>>()?;
+    cards_load(&cards, override_builtin)
+}
+
+fn parse_card(d: &Bound<'_, PyDict>, override_builtin: bool) -> PyResult<Card> {
+    let name: String = required(d, "name")?;
+    let upgraded = optional(d, "upgraded")?.unwrap_or(false);
+
+    let mut card = match CardName::from_name(&name) {
+        Some(id) if id.is_builtin() => {
+            if !override_builtin {
+                return Err(builtin("card", &name));
+            }
+            get_card(id, upgraded)
+        }
+        _ => Card {
+            name: registry::card_register(&name),
+            kind: variant("card kind", &required::<String>(d, "kind")?, CARD_KINDS)?,
+            color: CardColor::Colorless,
+            rarity: variant("card rarity", &required::<String>(d, "rarity")?, CARD_RARITIES)?,
+            cost: required(d, "cost")?,
+            x_cost: false,
+            upgraded,
+            exhaust: false,
+            innate: false,
+            ethereal: false,
+            unplayable: false,
+            effects: parse_effects(&required::<Vec<Bound<'_, PyDict>>>(d, "effects")?)?,
+            end_of_turn: &[],
+            on_draw: &[],
+        },
+    };
+    card.upgraded = upgraded;
+    if let Some(kind) = optional::<String>(d, "kind")? {
+        card.kind = variant("card kind", &kind, CARD_KINDS)?;
+    }
+    if let Some(color) = optional::<String>(d, "color")? {
+        card.color = variant("card color", &color, CARD_COLORS)?;
+    }
+    if let Some(rarity) = optional::<String>(d, "rarity")? {
+        card.rarity = variant("card rarity", &rarity, CARD_RARITIES)?;
+    }
+    card.cost = optional(d, "cost")?.unwrap_or(card.cost);
+    card.x_cost = optional(d, "x_cost")?.unwrap_or(card.x_cost);
+    card.exhaust = optional(d, "exhaust")?.unwrap_or(card.exhaust);
+    card.innate = optional(d, "innate")?.unwrap_or(card.innate);
+    card.ethereal = optional(d, "ethereal")?.unwrap_or(card.ethereal);
+    card.unplayable = optional(d, "unplayable")?.unwrap_or(card.unplayable);
+    if let Some(effects) = optional::<Vec<Bound<'_, PyDict>>>(d, "effects")? {
+        card.effects = parse_effects(&effects)?;
+    }
+    if let Some(effects) = optional::<Vec<Bound<'_, PyDict>>>(d, "end_of_turn")? {
+        card.end_of_turn = parse_effects(&effects)?;
+    }
+    if let Some(effects) = optional::<Vec<Bound<'_, PyDict>>>(d, "on_draw")? {
+        card.on_draw = parse_effects(&effects)?;
+    }
+    Ok(card)
+}
+
+// ---------------------------------------------------------------------------
+// Monsters
+// ---------------------------------------------------------------------------
+
+/// Each dict fully defines a monster; it registers a new monster or, with
+/// `override_builtin`, replaces a built-in's spawn and AI for monsters spawned
+/// from now on. Per-ascension values are lists of tiers keyed by "ascension",
+/// the last one reached applies:
+///
+/// {"name", "kind", "health": [[asc, min, max]],
+///  "modifiers": [{"ascension", "stacks": {kind: stacks | [min, max]}}],
+///  "moves": [{"name", "tiers": [{"ascension", "effects", "intent": {"kind", "damage", "instances"}}]}],
+///  "ai": [{"ascension", <rule>}]}
+///
+/// A move tier may give "variants": [{"effects", "intent"}] instead, one of
+/// which is rolled at spawn. An intent without a "kind" is an Attack when it
+/// has damage and Unknown otherwise. A rule is one of:
+///
+/// {"move"} | {"opening", "cycle"} | {"opening", "sequence"}
+/// | {"opening", "rolls": [{"move", "weight", "max_in_a_row"}]}
+/// | {"branches": [{"when": <condition>, <rule>}]}
+///
+/// where the last branch has no "when", and a condition is
+/// `{"type": <MoveCondition variant>, <field>: <value>, ...}` with "move"
+/// naming the move of `UsedWithin` and `UsedTimes`.
+pub fn monsters_load(defs: &[Bound<'_, PyDict>], override_builtin: bool) -> PyResult<()> {
+    let monsters = defs
+        .iter()
+        .map(|d| parse_monster(d, override_builtin))
+        .collect::<PyResult<Vec<_>>>()?;
+    registry::monsters_insert(monsters);
+    Ok(())
+}
+
+fn parse_monster(d: &Bound<'_, PyDict>, override_builtin: bool) -> PyResult<MonsterDef> {
+    let name: String = required(d, "name")?;
+    if !override_builtin && MonsterName::from_name(&name).is_some_and(|m| m.is_builtin()) {
+        return Err(builtin("monster", &name));
+    }
+    let name = registry::monster_register(&name);
+    let kind = match optional::<String>(d, "kind")? {
+        Some(kind) => variant("monster kind", &kind, MONSTER_KINDS)?,
+        None => MonsterKind::Normal,
+    };
+
+    let health = required::<Vec<[u16; 3]>>(d, "health")?
+        .into_iter()
+        .map(|[asc, min, max]| (asc as u8, (min, max.max(min))))
+        .collect::<Vec<_>>();
+
+    let mut modifiers = vec![];
+    for m in optional::<Vec<Bound<'_, PyDict>>>(d, "modifiers")?.unwrap_or_default() {
+        let asc = optional(&m, "ascension")?.unwrap_or(0);
+        let mut stacks = vec![];
+        for (kind, amount) in required::<Bound<'_, PyDict>>(&m, "stacks")?.iter() {
+            let kind: String = kind.extract()?;
+            let kind = ModifierKind::from_name(&kind).ok_or_else(|| unknown("modifier", &kind))?;
+            let range = match amount.extract::<i16>() {
+                Ok(n) => (n, n),
+                Err(_) => {
+                    let [min, max] = amount.extract::<[i16; 2]>()?;
+                    (min, max.max(min))
+                }
+            };
+            stacks.push((kind, range));
+        }
+        modifiers.push((asc, registry::intern(stacks).as_slice()));
+    }
+    if modifiers.is_empty() {
+        modifiers.push((0, &[][..]));
+    }
+
+    let moves = required::<Vec<Bound<'_, PyDict>>>(d, "moves")?
+        .iter()
+        .map(parse_move)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut rule = vec![];
+    for ai in required::<Vec<Bound<'_, PyDict>>>(d, "ai")? {
+        let asc = optional(&ai, "ascension")?.unwrap_or(0);
+        rule.push((asc, parse_rule(&ai, &moves)?));
+    }
+
+    Ok(MonsterDef {
+        name,
+        kind,
+        health: tiers(health)?,
+        modifiers: tiers(modifiers)?,
+        moves: registry::intern(moves).as_slice(),
+        rule: tiers(rule)?,
+    })
+}
+
+fn parse_move(d: &Bound<'_, PyDict>) -> PyResult<MoveDef> {
+    let name: String = required(d, "name")?;
+    let mut move_tiers = vec![];
+    for t in required::<Vec<Bound<'_, PyDict>>>(d, "tiers")? {
+        let variants = match optional::<Vec<Bound<'_, PyDict>>>(&t, "variants")? {
+            Some(variants) => variants.iter().map(parse_move_variant).collect::<PyResult<Vec<_>>>()?,
+            None => vec![parse_move_variant(&t)?],
+        };
+        if variants.is_empty() {
+            return Err(PyValueError::new_err("variants need at least one entry"));
+        }
+        move_tiers.push((optional(&t, "ascension")?.unwrap_or(0), registry::intern(variants).as_slice()));
+    }
+    Ok(MoveDef {
+        name: registry::intern(name).as_str(),
+        tiers: tiers(move_tiers)?,
+    })
+}
+
+fn parse_move_variant(d: &Bound<'_, PyDict>) -> PyResult<(&'static [EffectTemplate], Intent)> {
+    let effects = parse_effects(&required::<Vec<Bound<'_, PyDict>>>(d, "effects")?)?;
+    let i: Bound<'_, PyDict> = required(d, "intent")?;
+    let damage: Option<u16> = optional(&i, "damage")?;
+    let kind = match optional::<String>(&i, "kind")? {
+        Some(kind) => variant("intent kind", &kind, INTENT_KINDS)?,
+        None if damage.is_some() => IntentKind::Attack,
+        None => IntentKind::Unknown,
+    };
+    let intent = Intent {
+        damage,
+        instances: optional(&i, "instances")?,
+        kind,
+    };
+    Ok((effects, intent))
+}
+
+fn parse_rule(d: &Bound<'_, PyDict>, moves: &[MoveDef]) -> PyResult<MoveRule> {
+    if let Some(name) = optional::<String>(d, "move")? {
+        return Ok(MoveRule::Fixed(move_index(moves, &name)?));
+    }
+
+    if let Some(branches) = optional::<Vec<Bound<'_, PyDict>>>(d, "branches")? {
+        let branches = branches
+            .iter()
+            .map(|b| {
+                let condition = match optional::<Bound<'_, PyDict>>(b, "when")? {
+                    Some(c) => parse_move_condition(&c, moves)?,
+                    None => MoveCondition::Always,
+                };
+                Ok((condition, parse_rule(b, moves)?))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if branches.last().is_none_or(|(c, _)| *c != MoveCondition::Always) {
+            return Err(PyValueError::new_err("the last branch can't have a condition"));
+        }
+        return Ok(MoveRule::Branch {
+            branches: registry::intern(branches).as_slice(),
+        });
+    }
+
+    let opening = move_list(d, "opening", moves)?;
+    if let Some(rolls) = optional::<Vec<Bound<'_, PyDict>>>(d, "rolls")? {
+        let rolls = rolls
+            .iter()
+            .map(|r| {
+                Ok(MoveRoll {
+                    move_idx: move_index(moves, &required::<String>(r, "move")?)?,
+                    weight: required(r, "weight")?,
+                    max_in_a_row: optional(r, "max_in_a_row")?.unwrap_or(usize::MAX),
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if rolls.iter().all(|r| r.weight == 0) {
+            return Err(PyValueError::new_err("rolls need a positive weight"));
+        }
+        return Ok(MoveRule::Weighted {
+            opening,
+            rolls: registry::intern(rolls).as_slice(),
+        });
+    }
+
+    let sequence = move_list(d, "sequence", moves)?;
+    if !sequence.is_empty() {
+        return Ok(MoveRule::Sequence { opening, cycle: sequence });
+    }
+    let cycle = move_list(d, "cycle", moves)?;
+    if cycle.is_empty() {
+        return Err(PyValueError::new_err("ai needs a move, cycle, sequence, rolls or branches"));
+    }
+    Ok(MoveRule::Cycle { opening, cycle })
+}
+
+fn parse_move_condition(d: &Bound<'_, PyDict>, moves: &[MoveDef]) -> PyResult<MoveCondition> {
+    let kind: String = required(d, "type")?;
+    let condition = match kind.as_str() {
+        "Always" => MoveCondition::Always,
+        "TurnBelow" => MoveCondition::TurnBelow {
+            turns: required(d, "turns")?,
+        },
+        "TurnSlot" => {
+            let every: usize = required(d, "every")?;
+            if every == 0 {
+                return Err(PyValueError::new_err("every needs to be positive"));
+            }
+            MoveCondition::TurnSlot {
+                every,
+                slot: required(d, "slot")?,
+            }
+        }
+        "UsedWithin" => MoveCondition::UsedWithin {
+            move_idx: move_index(moves, &required::<String>(d, "move")?)?,
+            turns: required(d, "turns")?,
+        },
+        "UsedTimes" => MoveCondition::UsedTimes {
+            move_idx: move_index(moves, &required::<String>(d, "move")?)?,
+            times: required(d, "times")?,
+        },
+        "HasModifier" => MoveCondition::HasModifier { kind: modifier_kind(d)? },
+        "LacksModifier" => MoveCondition::LacksModifier { kind: modifier_kind(d)? },
+        "AlliesBelow" => MoveCondition::AlliesBelow {
+            count: required(d, "count")?,
+        },
+        "HealthBelowHalf" => MoveCondition::HealthBelowHalf,
+        _ => return Err(unknown("move condition", &kind)),
+    };
+    Ok(condition)
+}
+
+/// Moves are named in rules; the first move with the name is used.
+fn move_index(moves: &[MoveDef], name: &str) -> PyResult<usize> {
+    moves
+        .iter()
+        .position(|mv| mv.name == name)
+        .ok_or_else(|| unknown("move", name))
+}
+
+fn move_list(d: &Bound<'_, PyDict>, key: &str, moves: &[MoveDef]) -> PyResult<&'static [usize]> {
+    let idx = optional::<Vec<String>>(d, key)?
+        .unwrap_or_default()
+        .iter()
+        .map(|m| move_index(moves, m))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(registry::intern(idx).as_slice())
+}
+
+/// Sorted by ascension; the first tier must start at 0 so every ascension has a value.
+fn tiers<T: PartialEq + Send + Sync + 'static>(mut tiers: Vec<(u8, T)>) -> PyResult<&'static [(u8, T)]> {
+    tiers.sort_by_key(|(asc, _)| *asc);
+    if tiers.first().is_none_or(|(asc, _)| *asc != 0) {
+        return Err(PyValueError::new_err("tiers must start at ascension 0"));
+    }
+    Ok(registry::intern(tiers).as_slice())
+}
+
+// ---------------------------------------------------------------------------
+// Modifiers
+// ---------------------------------------------------------------------------
+
+/// Registers new modifier kinds: {"name", "is_buff", "stacks_duration",
+/// "min_stacks", "max_stacks", "hooks"}. Hooks are code, so "hooks" names a
+/// built-in modifier whose hooks to share; without it the modifier only counts
+/// stacks for effects to read.
+pub fn modifiers_load(defs: &[Bound<'_, PyDict>]) -> PyResult<()> {
+    for d in defs {
+        let name: String = required(d, "name")?;
+        let hooks = match optional::<String>(d, "hooks")? {
+            Some(kind) => {
+                let kind = ModifierKind::from_name(&kind).ok_or_else(|| unknown("modifier", &kind))?;
+                modifier_def(kind).hooks
+            }
+            None => HOOKS_NONE,
+        };
+        let is_buff = optional(d, "is_buff")?.unwrap_or(true);
+        let stacks_duration = optional(d, "stacks_duration")?.unwrap_or(false);
+        let min_stacks = optional(d, "min_stacks")?.unwrap_or(1);
+        let max_stacks = optional(d, "max_stacks")?.unwrap_or(999);
+
+        registry::modifier_register(&name, |kind| Modifier {
+            kind,
+            is_buff,
+            stacks_duration,
+            min_stacks,
+            max_stacks,
+            hooks,
+        })
+        .map_err(PyValueError::new_err)?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Encounters
+// ---------------------------------------------------------------------------
+
+/// Adds fights to an act's pools: {"name", "act", "pool", "weight", "monsters"}.
+/// A monster entry is a name or, in Weak/Strong pools, a list to pick one
+/// from. Weights use the doubled scale of the built-in tables.
+pub fn encounters_load(defs: &[Bound<'_, PyDict>]) -> PyResult<()> {
+    let mut encounters = vec![];
+    let mut groups = vec![];
+    for d in defs {
+        let act: u8 = required(d, "act")?;
+        let pool = variant("encounter pool", &required::<String>(d, "pool")?, ENCOUNTER_POOLS)?;
+        let mut slots = vec![];
+        for m in required::<Vec<Bound<'_, PyAny>>>(d, "monsters")? {
+            let slot = match m.extract::<String>() {
+                Ok(name) => EncounterSlot::Fixed(spawnable(&name)?),
+                Err(_) => {
+                    let names = m.extract::<Vec<String>>()?;
+                    let names = names.iter().map(|n| spawnable(n)).collect::<PyResult<Vec<_>>>()?;
+                    EncounterSlot::OneOf(registry::intern(names).as_slice())
+                }
+            };
+            slots.push(slot);
+        }
+
+        match pool {
+            EncounterPool::Weak | EncounterPool::Strong => encounters.push((
+                act,
+                pool,
+                Encounter {
+                    name: registry::intern(required::<String>(d, "name")?).as_str(),
+                    weight: optional(d, "weight")?.unwrap_or(2),
+                    slots: registry::intern(slots).as_slice(),
+                },
+            )),
+            EncounterPool::Elite | EncounterPool::Boss => {
+                let names = slots
+                    .iter()
+                    .map(|slot| match slot {
+                        EncounterSlot::Fixed(name) => Ok(*name),
+                        EncounterSlot::OneOf(_) | EncounterSlot::FromBag(_) => {
+                            Err(PyValueError::new_err("elite and boss fights name their monsters"))
+                        }
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                groups.push((act, pool, registry::intern(names).as_slice()));
+            }
+        }
+    }
+    registry::encounters_insert(encounters, groups);
+    Ok(())
+}
+
+/// Registered monsters can only be spawned once they have a definition.
+fn spawnable(name: &str) -> PyResult<MonsterName> {
+    MonsterName::from_name(name)
+        .filter(|m| m.is_builtin() || monster_def(*m).is_some())
+        .ok_or_else(|| unknown("monster", name))
+}
+
+// ---------------------------------------------------------------------------
+// Effect templates
+// ---------------------------------------------------------------------------
+
+/// Loaded definitions live for the rest of the process, like the statics they
+/// replace; reloading the same effects reuses them.
+fn parse_effects(defs: &[Bound<'_, PyDict>]) -> PyResult<&'static [EffectTemplate]> {
+    let effects = defs.iter().map(parse_effect).collect::<PyResult<Vec<_>>>()?;
+    Ok(registry::intern(effects).as_slice())
+}
+
+/// The effects an `If`, `ForEach` or `Scaled` wraps; an empty list is an error.
+fn nested_effects(d: &Bound<'_, PyDict>, key: &str) -> PyResult<&'static [EffectTemplate]> {
+    let defs: Vec<Bound<'_, PyDict>> = required(d, key)?;
+    if defs.is_empty() {
+        return Err(PyValueError::new_err(format!("{key} needs at least one effect")));
+    }
+    parse_effects(&defs)
+}
+
+/// `{"type": <variant>, <field>: <value>, ...}` using the `EffectTemplate`
+/// variant and field names; enums are given by their variant names.
+fn parse_effect(d: &Bound<'_, PyDict>) -> PyResult<EffectTemplate> {
+    let kind: String = required(d, "type")?;
+    let tmpl = match kind.as_str() {
+        "DamagePhysical" => EffectTemplate::DamagePhysical {
+            base: required(d, "base")?,
+            target: target(d)?,
+        },
+        "DamagePhysicalPerHealth" => EffectTemplate::DamagePhysicalPerHealth {
+            divisor: required(d, "divisor")?,
+            target: target(d)?,
+        },
+        "DamageDeal" => EffectTemplate::DamageDeal {
+            amount: required(d, "amount")?,
+            target: target(d)?,
+        },
+        "BlockGain" => EffectTemplate::BlockGain {
+            amount: required(d, "amount")?,
+            target: target(d)?,
+        },
+        "HealthLoss" => EffectTemplate::HealthLoss {
+            amount: required(d, "amount")?,
+            target: target(d)?,
+        },
+        "ModifierGain" => EffectTemplate::ModifierGain {
+            kind: modifier_kind(d)?,
+            stacks: required(d, "stacks")?,
+            target: target(d)?,
+        },
+        "ModifierRemove" => EffectTemplate::ModifierRemove {
+            kind: modifier_kind(d)?,
+            target: target(d)?,
+        },
+        "ModifierMultiply" => EffectTemplate::ModifierMultiply {
+            kind: modifier_kind(d)?,
+            factor: required(d, "factor")?,
+            target: target(d)?,
+        },
+        "EnergyGain" => EffectTemplate::EnergyGain {
+            amount: required(d, "amount")?,
+        },
+        "EnergyLoss" => EffectTemplate::EnergyLoss {
+            amount: required(d, "amount")?,
+        },
+        "AddShivs" => EffectTemplate::AddShivs {
+            count: required(d, "count")?,
+        },
+        "CardDraw" => EffectTemplate::CardDraw {
+            count: required(d, "count")?,
+        },
+        "CardDiscard" => EffectTemplate::CardDiscard {
+            count: required(d, "count")?,
+            selection: variant("selection", &required::<String>(d, "selection")?, SELECTION_KINDS)?,
+        },
+        "CardExhaustRandom" => EffectTemplate::CardExhaustRandom {
+            count: required(d, "count")?,
+        },
+        "CardFetchRandom" => EffectTemplate::CardFetchRandom { pile: pile(d)? },
+        "CardAdd" => EffectTemplate::CardAdd {
+            name: card_name(d)?,
+            count: required(d, "count")?,
+            pile: pile(d)?,
+        },
+        "CardUpgradeAll" => EffectTemplate::CardUpgradeAll { name: card_name(d)? },
+        "CalculatedGamble" => EffectTemplate::CalculatedGamble,
+        "If" => EffectTemplate::If {
+            condition: condition(d)?,
+            then: nested_effects(d, "then")?,
+        },
+        "ForEach" => EffectTemplate::ForEach {
+            count: variant("count", &required::<String>(d, "count")?, COUNT_KINDS)?,
+            each: nested_effects(d, "each")?,
+        },
+        "Scaled" => EffectTemplate::Scaled {
+            count: variant("count", &required::<String>(d, "count")?, COUNT_KINDS)?,
+            bonus: optional(d, "bonus")?.unwrap_or(0),
+            each: nested_effects(d, "each")?,
+        },
+        "PlayableIf" => EffectTemplate::PlayableIf { condition: condition(d)? },
+        "MonsterSummon" => EffectTemplate::MonsterSummon {
+            pool: monster_names(d, "pool")?,
+            count: required(d, "count")?,
+        },
+        "MonsterSplit" => EffectTemplate::MonsterSplit {
+            into: monster_names(d, "into")?,
+        },
+        "Suicide" => EffectTemplate::Suicide,
+        "GoldSteal" => EffectTemplate::GoldSteal {
+            amount: required(d, "amount")?,
+        },
+        "Escape" => EffectTemplate::Escape,
+        "Recover" => EffectTemplate::Recover {
+            percent: required(d, "percent")?,
+        },
+        _ => return Err(unknown("effect type", &kind)),
+    };
+    Ok(tmpl)
+}
+
+/// `{"type": "TargetHas", "kind": <modifier>}`, `{"type": "DiscardedThisTurn"}`
+/// or `{"type": "DrawPileEmpty"}`.
+fn condition(d: &Bound<'_, PyDict>) -> PyResult<Condition> {
+    let c: Bound<'_, PyDict> = required(d, "condition")?;
+    let kind: String = required(&c, "type")?;
+    match kind.as_str() {
+        "TargetHas" => Ok(Condition::TargetHas { kind: modifier_kind(&c)? }),
+        "DiscardedThisTurn" => Ok(Condition::DiscardedThisTurn),
+        "DrawPileEmpty" => Ok(Condition::DrawPileEmpty),
+        _ => Err(unknown("condition", &kind)),
+    }
+}
+
+fn target(d: &Bound<'_, PyDict>) -> PyResult<TargetKind> {
+    variant("target", &required::<String>(d, "target")?, TARGET_KINDS)
+}
+
+fn pile(d: &Bound<'_, PyDict>) -> PyResult<PileKind> {
+    variant("pile", &required::<String>(d, "pile")?, PILE_KINDS)
+}
+
+fn modifier_kind(d: &Bound<'_, PyDict>) -> PyResult<ModifierKind> {
+    let kind: String = required(d, "kind")?;
+    ModifierKind::from_name(&kind).ok_or_else(|| unknown("modifier", &kind))
+}
+
+fn card_name(d: &Bound<'_, PyDict>) -> PyResult<CardName> {
+    let name: String = required(d, "name")?;
+    CardName::from_name(&name).ok_or_else(|| unknown("card", &name))
+}
+
+fn monster_names(d: &Bound<'_, PyDict>, key: &str) -> PyResult<&'static [MonsterName]> {
+    let names = required::<Vec<String>>(d, key)?
+        .iter()
+        .map(|n| MonsterName::from_name(n).ok_or_else(|| unknown("monster", n)))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(registry::intern(names).as_slice())
+}
+
+// ---------------------------------------------------------------------------
+// Dict helpers
+// ---------------------------------------------------------------------------
+
+fn optional<'py, T: FromPyObject<'py>>(d: &Bound<'py, PyDict>, key: &str) -> PyResult<Option<T>> {
+    d.get_item(key)?.map(|v| v.extract()).transpose()
+}
+
+fn required<'py, T: FromPyObject<'py>>(d: &Bound<'py, PyDict>, key: &str) -> PyResult<T> {
+    optional(d, key)?.ok_or_else(|| PyValueError::new_err(format!("missing field: {key}")))
+}
+
+/// Matches enum values by their variant name.
+fn variant<T: Debug>(what: &str, name: &str, options: impl IntoIterator<Item = T>) -> PyResult<T> {
+    options
+        .into_iter()
+        .find(|o| format!("{o:?}") == name)
+        .ok_or_else(|| unknown(what, name))
+}
+
+fn unknown(what: &str, name: &str) -> PyErr {
+    PyValueError::new_err(format!("unknown {what}: {name}"))
+}
+
+fn builtin(what: &str, name: &str) -> PyErr {
+    PyValueError::new_err(format!("built-in {what}: {name} (pass override=True to replace it)"))
+}
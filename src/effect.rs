@@ -1,7 +1,7 @@
 // Effect system: runtime effects + card/monster-level effect templates.
 
 use crate::modifier::ModifierKind;
-use crate::types::ActorId;
+use crate::types::{ActorId, CardName, ChestSize, KeyKind, MonsterName};
 
 // ---------------------------------------------------------------------------
 // EffectTemplate: stored on Card and Move, used for RL encoding + instantiation
@@ -12,6 +12,9 @@ pub enum TargetKind {
     CardTarget,
     Character,
     AllMonsters,
+    RandomMonster,
+    AllAllies,   // every other living monster
+    RandomAlly,  // one other living monster
     Source,
 }
 
@@ -21,16 +24,57 @@ pub enum SelectionKind {
     Random,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageKind {
+    Attack, // Strength, Weak, Vulnerable, ...
+    Thorns, // flat, still blocked
+    HpLoss, // flat, ignores block
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PileKind {
+    Draw,
+    Discard,
+}
+
+/// Checked when the templates are instantiated, before any of them resolves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    TargetHas { kind: ModifierKind }, // the card's target
+    DiscardedThisTurn,
+    DrawPileEmpty,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CountKind {
+    AttacksPlayedThisTurn, // before the card being played
+    SkillsInHand,
+    CardsInHand,
+    XCost, // energy an X-cost card spends, plus Chemical X
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EffectTemplate {
     DamagePhysical {
         base: u16,
         target: TargetKind,
     },
+    DamagePhysicalPerHealth {
+        divisor: u16, // base = target's current health / divisor + 1
+        target: TargetKind,
+    },
+    DamageDeal {
+        amount: u16, // flat, still blocked
+        target: TargetKind,
+    },
     BlockGain {
         amount: u16,
         target: TargetKind,
     },
+    HealthLoss {
+        amount: u16,
+        target: TargetKind,
+    },
     ModifierGain {
         kind: ModifierKind,
         stacks: i16,
@@ -40,9 +84,17 @@ pub enum EffectTemplate {
         kind: ModifierKind,
         target: TargetKind,
     },
+    ModifierMultiply {
+        kind: ModifierKind,
+        factor: i16,
+        target: TargetKind,
+    },
     EnergyGain {
         amount: u8,
     },
+    EnergyLoss {
+        amount: u8,
+    },
     AddShivs {
         count: u8,
     },
@@ -50,9 +102,59 @@ pub enum EffectTemplate {
         count: u8,
     },
     CardDiscard {
+        count: u8,
         selection: SelectionKind,
     },
+    CardExhaustRandom {
+        count: u8,
+    },
+    CardFetchRandom {
+        pile: PileKind,
+    },
+    CardAdd {
+        name: CardName,
+        count: u8,
+        pile: PileKind,
+    },
+    CardUpgradeAll {
+        name: CardName,
+    },
     CalculatedGamble,
+    // Branching
+    If {
+        condition: Condition,
+        then: &'static [EffectTemplate],
+    },
+    ForEach {
+        count: CountKind,
+        each: &'static [EffectTemplate],
+    },
+    // Applied once with each template's amount multiplied by count + bonus, so
+    // "lose X Strength" is a single debuff rather than X of them
+    Scaled {
+        count: CountKind,
+        bonus: u8,
+        each: &'static [EffectTemplate],
+    },
+    PlayableIf {
+        condition: Condition, // the card can't be played otherwise
+    },
+    // Monster-only
+    MonsterSummon {
+        pool: &'static [MonsterName],
+        count: u8,
+    },
+    MonsterSplit {
+        into: &'static [MonsterName],
+    },
+    Suicide,
+    GoldSteal {
+        amount: u16,
+    },
+    Escape,
+    Recover {
+        percent: u8, // removes the source's debuffs and heals it up to this share of its max HP
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -72,9 +174,18 @@ pub enum Effect {
         card_idx: usize,
     },
     CardDiscardAll,
+    CardDiscardRandom {
+        count: u8,
+    },
     CardExhaust {
         card_idx: usize,
     },
+    CardExhaustRandom {
+        count: u8,
+    },
+    CardFetchRandom {
+        pile: PileKind,
+    },
     CardRemove {
         card_idx: usize,
     },
@@ -82,13 +193,25 @@ pub enum Effect {
         card_idx: usize,
     },
     CardActiveClear,
+    CardReplay {
+        card_idx: usize,
+        x: Option<u16>, // X of the play being copied
+    },
     AddShivs {
         count: u8,
     },
+    CardAdd {
+        name: CardName,
+        count: u8,
+        pile: PileKind,
+    },
     CalculatedGamble,
     CardUpgrade {
         deck_idx: usize,
     },
+    CardUpgradeAll {
+        name: CardName,
+    },
 
     // Card rewards
     CardRewardRoll,
@@ -97,6 +220,26 @@ pub enum Effect {
     },
     CardRewardClear,
 
+    // Treasure
+    ChestOpen {
+        size: ChestSize,
+    },
+    BossChestOpen,
+    RelicRewardSelect {
+        reward_idx: usize,
+    },
+    RelicRewardClear,
+    GoldGain {
+        amount: u16,
+    },
+    GoldSteal {
+        source: ActorId,
+        amount: u16,
+    },
+    KeyObtain {
+        key: KeyKind,
+    },
+
     // Targeting
     TargetSet {
         monster_idx: u8,
@@ -108,6 +251,8 @@ pub enum Effect {
         source: ActorId,
         target: ActorId,
         base: u16,
+        kind: DamageKind,
+        card: Option<CardName>,
     },
     DamageDeal {
         target: ActorId,
@@ -149,6 +294,16 @@ pub enum Effect {
         target: ActorId,
         kind: ModifierKind,
     },
+    ModifierSet {
+        target: ActorId,
+        kind: ModifierKind,
+        stacks: i16,
+    },
+    ModifierMultiply {
+        target: ActorId,
+        kind: ModifierKind,
+        factor: i16,
+    },
     ModifierTick {
         target: ActorId,
     },
@@ -158,6 +313,9 @@ pub enum Effect {
     Death {
         actor: ActorId,
     },
+    Escape {
+        actor: ActorId,
+    },
     CombatStart,
     CombatEnd,
     TurnStart {
@@ -166,14 +324,28 @@ pub enum Effect {
     TurnEnd {
         actor: ActorId,
     },
+    MonsterMove {
+        monster_idx: u8,
+    },
+    MonsterSpawn {
+        name: MonsterName,
+        health: Option<u16>,
+        minion: bool,
+    },
+    MonsterSplit {
+        monster_idx: u8,
+        into: &'static [MonsterName],
+    },
     MonsterMoveUpdate {
         monster_idx: u8,
     },
     RoomEnter,
+    ActAdvance,
     GameEnd,
 
     // Await input (pause the queue)
     AwaitMapNode,
     AwaitCardReward,
+    AwaitRelicReward,
     AwaitDiscard,
 }
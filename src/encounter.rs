@@ -0,0 +1,248 @@
+// Encounter tables: which monsters are spawned in each act.
+
+use crate::monster::GREMLIN_POOL;
+use crate::registry;
+use crate::types::MonsterName;
+
+pub const ACT_FIRST: u8 = 1;
+pub const ACT_LAST: u8 = 3;
+pub const ACT_FINAL: u8 = 4;
+
+// ---------------------------------------------------------------------------
+// Normal encounters (static data)
+// ---------------------------------------------------------------------------
+
+/// One monster slot: a fixed monster, or a random pick from a pool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncounterSlot {
+    Fixed(MonsterName),
+    OneOf(&'static [MonsterName]),
+    /// Slots of one encounter sharing a bag draw from it without replacement.
+    FromBag(&'static [MonsterName]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Encounter {
+    pub name: &'static str, // entries sharing a name count as one for no-repeat rules
+    pub weight: u8,         // relative, doubled so half weights stay integral
+    pub slots: &'static [EncounterSlot],
+}
+
+/// Which table of an act an encounter belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncounterPool {
+    Weak,
+    Strong,
+    Elite,
+    Boss,
+}
+
+use EncounterSlot::{Fixed, FromBag, OneOf};
+
+/// Normal fights drawn from the weak pool at the start of each act.
+pub const ENCOUNTERS_WEAK_ACT_1: usize = 3;
+pub const ENCOUNTERS_WEAK: usize = 2;
+
+static LOUSE: &[MonsterName] = &[MonsterName::LouseGreen, MonsterName::LouseRed];
+static LOUSE_OR_MEDIUM_SLIME: &[MonsterName] = &[
+    MonsterName::LouseGreen,
+    MonsterName::LouseRed,
+    MonsterName::SpikeSlimeM,
+    MonsterName::AcidSlimeM,
+];
+static THUG: &[MonsterName] = &[
+    MonsterName::Looter,
+    MonsterName::Cultist,
+    MonsterName::BlueSlaver,
+    MonsterName::RedSlaver,
+];
+static WILDLIFE: &[MonsterName] = &[MonsterName::FungiBeast, MonsterName::JawWorm];
+
+static ACT_1_WEAK: &[Encounter] = &[
+    Encounter { name: "Cultist", weight: 4, slots: &[Fixed(MonsterName::Cultist)] },
+    Encounter { name: "Jaw Worm", weight: 4, slots: &[Fixed(MonsterName::JawWorm)] },
+    Encounter { name: "2 Louse", weight: 4, slots: &[OneOf(LOUSE), OneOf(LOUSE)] },
+    Encounter {
+        name: "Small Slimes",
+        weight: 2,
+        slots: &[Fixed(MonsterName::SpikeSlimeS), Fixed(MonsterName::AcidSlimeM)],
+    },
+    Encounter {
+        name: "Small Slimes",
+        weight: 2,
+        slots: &[Fixed(MonsterName::AcidSlimeS), Fixed(MonsterName::SpikeSlimeM)],
+    },
+];
+
+static ACT_1_STRONG: &[Encounter] = &[
+    Encounter {
+        name: "Gremlin Gang",
+        weight: 2,
+        slots: &[FromBag(&GREMLIN_POOL), FromBag(&GREMLIN_POOL), FromBag(&GREMLIN_POOL), FromBag(&GREMLIN_POOL)],
+    },
+    Encounter { name: "Large Slime", weight: 2, slots: &[Fixed(MonsterName::AcidSlimeL)] },
+    Encounter { name: "Large Slime", weight: 2, slots: &[Fixed(MonsterName::SpikeSlimeL)] },
+    Encounter {
+        name: "Lots of Slimes",
+        weight: 2,
+        slots: &[
+            Fixed(MonsterName::SpikeSlimeS),
+            Fixed(MonsterName::SpikeSlimeS),
+            Fixed(MonsterName::SpikeSlimeS),
+            Fixed(MonsterName::AcidSlimeS),
+            Fixed(MonsterName::AcidSlimeS),
+        ],
+    },
+    Encounter { name: "Blue Slaver", weight: 4, slots: &[Fixed(MonsterName::BlueSlaver)] },
+    Encounter { name: "Red Slaver", weight: 2, slots: &[Fixed(MonsterName::RedSlaver)] },
+    Encounter { name: "3 Louse", weight: 4, slots: &[OneOf(LOUSE), OneOf(LOUSE), OneOf(LOUSE)] },
+    Encounter {
+        name: "2 Fungi Beasts",
+        weight: 4,
+        slots: &[Fixed(MonsterName::FungiBeast), Fixed(MonsterName::FungiBeast)],
+    },
+    Encounter { name: "Exordium Thugs", weight: 3, slots: &[OneOf(LOUSE_OR_MEDIUM_SLIME), OneOf(THUG)] },
+    Encounter { name: "Exordium Wildlife", weight: 3, slots: &[OneOf(WILDLIFE), OneOf(LOUSE_OR_MEDIUM_SLIME)] },
+    Encounter { name: "Looter", weight: 4, slots: &[Fixed(MonsterName::Looter)] },
+];
+
+static SHAPES: [MonsterName; 6] = [
+    MonsterName::Repulsor,
+    MonsterName::Repulsor,
+    MonsterName::Exploder,
+    MonsterName::Exploder,
+    MonsterName::Spiker,
+    MonsterName::Spiker,
+];
+
+static ACT_2_WEAK: &[Encounter] = &[
+    Encounter { name: "Chosen", weight: 2, slots: &[Fixed(MonsterName::Chosen)] },
+    Encounter {
+        name: "3 Byrds",
+        weight: 2,
+        slots: &[Fixed(MonsterName::Byrd), Fixed(MonsterName::Byrd), Fixed(MonsterName::Byrd)],
+    },
+    Encounter {
+        name: "2 Thieves",
+        weight: 2,
+        slots: &[Fixed(MonsterName::Looter), Fixed(MonsterName::Mugger)],
+    },
+];
+
+static ACT_2_STRONG: &[Encounter] = &[
+    Encounter {
+        name: "Chosen and Byrds",
+        weight: 2,
+        slots: &[Fixed(MonsterName::Byrd), Fixed(MonsterName::Chosen)],
+    },
+    Encounter {
+        name: "Cultist and Chosen",
+        weight: 3,
+        slots: &[Fixed(MonsterName::Cultist), Fixed(MonsterName::Chosen)],
+    },
+    Encounter {
+        name: "3 Cultists",
+        weight: 3,
+        slots: &[Fixed(MonsterName::Cultist), Fixed(MonsterName::Cultist), Fixed(MonsterName::Cultist)],
+    },
+];
+
+static ACT_3_WEAK: &[Encounter] = &[
+    Encounter { name: "Orb Walker", weight: 2, slots: &[Fixed(MonsterName::OrbWalker)] },
+    Encounter {
+        name: "3 Shapes",
+        weight: 2,
+        slots: &[FromBag(&SHAPES), FromBag(&SHAPES), FromBag(&SHAPES)],
+    },
+];
+
+static ACT_3_STRONG: &[Encounter] = &[
+    Encounter {
+        name: "4 Shapes",
+        weight: 1,
+        slots: &[FromBag(&SHAPES), FromBag(&SHAPES), FromBag(&SHAPES), FromBag(&SHAPES)],
+    },
+    Encounter {
+        name: "Jaw Worm Horde",
+        weight: 1,
+        slots: &[Fixed(MonsterName::JawWorm), Fixed(MonsterName::JawWorm), Fixed(MonsterName::JawWorm)],
+    },
+    Encounter { name: "Writhing Mass", weight: 1, slots: &[Fixed(MonsterName::WrithingMass)] },
+];
+
+// ---------------------------------------------------------------------------
+// Elite encounters (static data)
+// ---------------------------------------------------------------------------
+
+static ACT_1_ELITE: &[&[MonsterName]] = &[&[MonsterName::GremlinNob]];
+
+static ACT_2_ELITE: &[&[MonsterName]] = &[
+    &[MonsterName::BlueSlaver, MonsterName::Taskmaster, MonsterName::RedSlaver],
+    &[MonsterName::MadGremlin, MonsterName::SneakyGremlin, MonsterName::GremlinLeader],
+];
+
+static ACT_3_ELITE: &[&[MonsterName]] = &[
+    &[MonsterName::GiantHead],
+    &[MonsterName::Dagger, MonsterName::Reptomancer, MonsterName::Dagger],
+];
+
+static ACT_4_ELITE: &[&[MonsterName]] = &[&[MonsterName::SpireShield, MonsterName::SpireSpear]];
+
+// ---------------------------------------------------------------------------
+// Boss encounters (static data)
+// ---------------------------------------------------------------------------
+
+static ACT_1_BOSS: &[&[MonsterName]] = &[
+    &[MonsterName::TheGuardian],
+    &[MonsterName::Hexaghost],
+    &[MonsterName::SlimeBoss],
+];
+static ACT_2_BOSS: &[&[MonsterName]] = &[&[MonsterName::TheChamp]];
+static ACT_3_BOSS: &[&[MonsterName]] = &[
+    &[MonsterName::Cultist, MonsterName::Cultist, MonsterName::AwakenedOne],
+    &[MonsterName::TimeEater],
+    &[MonsterName::Deca, MonsterName::Donu],
+];
+static ACT_4_BOSS: &[&[MonsterName]] = &[&[MonsterName::CorruptHeart]];
+
+// ---------------------------------------------------------------------------
+// Lookup
+// ---------------------------------------------------------------------------
+
+/// Registered encounters follow the built-in ones.
+pub fn encounters_normal(act: u8, weak: bool) -> Vec<&'static Encounter> {
+    let table = match (act, weak) {
+        (1, true) => ACT_1_WEAK,
+        (1, false) => ACT_1_STRONG,
+        (2, true) => ACT_2_WEAK,
+        (2, false) => ACT_2_STRONG,
+        (3, true) => ACT_3_WEAK,
+        (3, false) => ACT_3_STRONG,
+        _ => panic!("No normal encounters for act {act}"),
+    };
+    let pool = if weak { EncounterPool::Weak } else { EncounterPool::Strong };
+    table.iter().chain(registry::encounters(act, pool)).collect()
+}
+
+pub fn encounters_elite(act: u8) -> Vec<&'static [MonsterName]> {
+    let table = match act {
+        1 => ACT_1_ELITE,
+        2 => ACT_2_ELITE,
+        3 => ACT_3_ELITE,
+        4 => ACT_4_ELITE,
+        _ => panic!("No elite encounters for act {act}"),
+    };
+    table.iter().copied().chain(registry::groups(act, EncounterPool::Elite)).collect()
+}
+
+/// The act boss is rolled once, when the act's map is generated.
+pub fn encounters_boss(act: u8) -> Vec<&'static [MonsterName]> {
+    let table = match act {
+        1 => ACT_1_BOSS,
+        2 => ACT_2_BOSS,
+        3 => ACT_3_BOSS,
+        4 => ACT_4_BOSS,
+        _ => panic!("No boss encounter for act {act}"),
+    };
+    table.iter().copied().chain(registry::groups(act, EncounterPool::Boss)).collect()
+}
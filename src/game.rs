@@ -9,12 +9,15 @@ use crate::action::{Action, handle_action};
 use crate::cards::Card;
 use crate::cards::get_card;
 use crate::effect::Effect;
+use crate::encounter::ACT_FIRST;
 use crate::map::generate_map;
 use crate::modifier::modifiers_new;
-use crate::process::process_queue;
+use crate::process::{process_queue, process_queue_front};
 use crate::state::*;
 use crate::types::*;
 
+const GOLD_START: u16 = 99;
+
 // ---------------------------------------------------------------------------
 // Create + initialize
 // ---------------------------------------------------------------------------
@@ -35,18 +38,25 @@ pub fn create_game_state(ascension: u8, seed: u64) -> GameState {
     };
 
     // Starter deck
-    let deck = silent_starter_deck();
+    let mut deck = silent_starter_deck();
+    if ascension >= 10 {
+        deck.push(get_card(CardName::AscendersBane, false));
+    }
 
     // Map
-    let map = generate_map(&mut rng);
+    let map = generate_map(ACT_FIRST, ascension, &mut rng);
 
     GameState {
         ascension,
+        act: ACT_FIRST,
         fsm: Fsm::Map, // will be set properly by initialize
         rng,
         character,
         energy: Energy { current: 3, max: 3 },
         deck,
+        relics: Vec::new(),
+        gold: GOLD_START,
+        keys: Keys::default(),
         combat_cards: Vec::new(),
         draw_pile: Vec::new(),
         hand: Vec::new(),
@@ -54,8 +64,12 @@ pub fn create_game_state(ascension: u8, seed: u64) -> GameState {
         exhaust_pile: Vec::new(),
         card_active: None,
         card_target: None,
+        card_x: None,
+        combat_history: CombatHistory::default(),
         monsters: Vec::new(),
+        encounter_history: Vec::new(),
         card_rewards: Vec::new(),
+        relic_rewards: Vec::new(),
         map,
         effect_queue: VecDeque::new(),
     }
@@ -79,6 +93,67 @@ pub fn step(state: &mut GameState, action: Action) {
     state.fsm = determine_fsm(state);
 }
 
+// ---------------------------------------------------------------------------
+// Preview
+// ---------------------------------------------------------------------------
+
+/// A card play simulated on a copy of the state. Random outcomes match what
+/// the real play would roll, since the copy carries the same RNG.
+pub struct CardPreview {
+    pub state: GameState,
+    pub cards_drawn: u8,
+}
+
+/// Plays the card in hand at `hand_idx` on a copy of `state`, on `target` when
+/// the card needs one, until the play resolves or waits for input.
+pub fn preview_card(state: &GameState, hand_idx: usize, target: Option<u8>) -> Result<CardPreview, String> {
+    if state.fsm != Fsm::CombatDefault {
+        return Err(format!("Can't play cards in state {:?}", state.fsm));
+    }
+    let card = match state.hand.get(hand_idx) {
+        Some(&card_idx) => state.combat_cards[card_idx],
+        None => return Err(format!("No card at hand index {hand_idx}")),
+    };
+    if card.unplayable || !card.cost_met(state.energy.current) || !card.conditions_met(state) {
+        return Err(format!("Card {:?} can't be played", card.name));
+    }
+
+    // Like a real play, a lone monster is targeted without asking
+    let alive: Vec<u8> = monster_ids_alive(state).collect();
+    let target = match (card.requires_target(), target, &alive[..]) {
+        (false, _, _) => None,
+        (true, Some(t), _) if alive.contains(&t) => Some(t),
+        (true, None, [only]) => Some(*only),
+        (true, _, _) => return Err(format!("Card {:?} needs a living target", card.name)),
+    };
+
+    let mut sim = state.clone();
+    let mut cards_drawn = 0;
+    let mut action = Some(Action::PlayCard { hand_idx });
+    while let Some(a) = action.take() {
+        let effects = handle_action(&mut sim, a);
+        sim.effect_queue.extend(effects);
+        loop {
+            let drawing = matches!(sim.effect_queue.front(), Some(Effect::CardDraw { .. }));
+            let hand = sim.hand.len();
+            let more = process_queue_front(&mut sim);
+            if drawing {
+                cards_drawn += sim.hand.len().saturating_sub(hand) as u8;
+            }
+            if !more {
+                break;
+            }
+        }
+        sim.fsm = determine_fsm(&sim);
+
+        if let (Fsm::CombatAwaitTarget, Some(monster_idx)) = (sim.fsm, target) {
+            action = Some(Action::SelectMonster { monster_idx });
+        }
+    }
+
+    Ok(CardPreview { state: sim, cards_drawn })
+}
+
 // ---------------------------------------------------------------------------
 // FSM determination
 // ---------------------------------------------------------------------------
@@ -90,6 +165,7 @@ pub fn determine_fsm(state: &GameState) -> Fsm {
             Effect::AwaitDiscard => Fsm::CombatAwaitDiscard,
             Effect::AwaitMapNode => Fsm::Map,
             Effect::AwaitCardReward => Fsm::CardReward,
+            Effect::AwaitRelicReward => Fsm::RelicReward,
             _ => panic!("Unexpected pending effect: {:?}", front),
         };
     }
@@ -100,8 +176,10 @@ pub fn determine_fsm(state: &GameState) -> Fsm {
 
     match state.map.active_room_type() {
         Some(RoomType::RestSite) => Fsm::RestSite,
-        Some(RoomType::CombatMonster) | Some(RoomType::CombatBoss) => Fsm::CombatDefault,
-        None => Fsm::Map,
+        Some(RoomType::CombatMonster)
+        | Some(RoomType::CombatElite)
+        | Some(RoomType::CombatBoss) => Fsm::CombatDefault,
+        Some(RoomType::Treasure) | None => Fsm::Map,
     }
 }
 
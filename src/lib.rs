@@ -1,24 +1,31 @@
 #![allow(dead_code)]
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 mod action;
 mod cards;
+mod content;
 mod effect;
+mod encounter;
 mod game;
 mod map;
 mod modifier;
 mod monster;
 mod process;
+mod registry;
+mod relic;
 mod state;
+mod text;
 mod types;
 mod view;
 
 use action::Action;
-use game::{create_game_state, initialize, step};
+use game::{create_game_state, initialize, preview_card, step};
 use view::{
-    ViewCard, ViewCharacter, ViewEffectTemplate, ViewEnergy, ViewGameState, ViewIntent, ViewMap,
-    ViewMapNode, ViewModifier, ViewMonster, build_view,
+    ViewCard, ViewCardPreview, ViewCharacter, ViewEffectTemplate, ViewEnergy, ViewGameState, ViewIntent,
+    ViewMap, ViewMapNode, ViewModifier, ViewMonster, ViewRelic, ViewTurn, build_view, build_view_card_preview,
 };
 
 #[pyclass]
@@ -47,6 +54,14 @@ impl GameEnv {
         (build_view(&self.state), done)
     }
 
+    /// What playing the card would do, without playing it. `target` is a
+    /// monster index, needed for targeted cards unless one monster is left.
+    #[pyo3(signature = (hand_idx, target=None))]
+    fn preview_card(&self, hand_idx: usize, target: Option<u8>) -> PyResult<ViewCardPreview> {
+        let preview = preview_card(&self.state, hand_idx, target).map_err(PyValueError::new_err)?;
+        Ok(build_view_card_preview(&self.state, &preview))
+    }
+
     fn reset(&mut self, seed: u64) -> ViewGameState {
         let asc = self.state.ascension;
         self.state = create_game_state(asc, seed);
@@ -63,6 +78,64 @@ impl GameEnv {
     }
 }
 
+/// Adds cards, or with `override=True` replaces built-in ones, for cards created
+/// from now on in every GameEnv of the process, e.g. with
+/// `tomllib.load(f)["cards"]` or `json.load(f)`. Names are identifiers
+/// ("DeadlyPoison"), like modifier and monster names.
+#[pyfunction]
+#[pyo3(signature = (cards, r#override=false))]
+fn load_cards(cards: Vec<Bound<'_, PyDict>>, r#override: bool) -> PyResult<()> {
+    content::cards_load(&cards, r#override)
+}
+
+/// Loads a card database file: TOML with `[[cards]]` tables or JSON with a
+/// "cards" list, each entry as for `load_cards`.
+#[pyfunction]
+#[pyo3(signature = (path, r#override=false))]
+fn load_cards_file(py: Python<'_>, path: &str, r#override: bool) -> PyResult<()> {
+    content::cards_load_file(py, path, r#override)
+}
+
+/// Restores the built-in card definitions in every GameEnv.
+#[pyfunction]
+fn clear_cards() {
+    registry::cards_clear();
+}
+
+/// Adds monsters, or with `override=True` replaces built-in ones (HP, moves,
+/// AI), for monsters spawned from now on in every GameEnv of the process.
+#[pyfunction]
+#[pyo3(signature = (monsters, r#override=false))]
+fn load_monsters(monsters: Vec<Bound<'_, PyDict>>, r#override: bool) -> PyResult<()> {
+    content::monsters_load(&monsters, r#override)
+}
+
+/// Restores the built-in monster definitions in every GameEnv.
+#[pyfunction]
+fn clear_monsters() {
+    registry::monsters_clear();
+}
+
+/// Registers modifier kinds that cards and monsters can then apply by name, in
+/// every GameEnv of the process.
+#[pyfunction]
+fn load_modifiers(modifiers: Vec<Bound<'_, PyDict>>) -> PyResult<()> {
+    content::modifiers_load(&modifiers)
+}
+
+/// Adds fights to the acts' encounter pools for maps generated from now on in
+/// every GameEnv of the process.
+#[pyfunction]
+fn load_encounters(encounters: Vec<Bound<'_, PyDict>>) -> PyResult<()> {
+    content::encounters_load(&encounters)
+}
+
+/// Removes the loaded encounters from every GameEnv.
+#[pyfunction]
+fn clear_encounters() {
+    registry::encounters_clear();
+}
+
 fn decode_action(action_type: u8, action_index: i32) -> Action {
     match action_type {
         0 => Action::PlayCard {
@@ -83,6 +156,11 @@ fn decode_action(action_type: u8, action_index: i32) -> Action {
         7 => Action::Upgrade {
             deck_idx: action_index as usize,
         },
+        8 => Action::SelectRelicReward {
+            reward_idx: action_index as usize,
+        },
+        9 => Action::SkipRelicReward,
+        10 => Action::Recall,
         _ => panic!("Unknown action type: {action_type}"),
     }
 }
@@ -90,8 +168,17 @@ fn decode_action(action_type: u8, action_index: i32) -> Action {
 #[pymodule]
 fn slai(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<GameEnv>()?;
+    m.add_function(wrap_pyfunction!(load_cards, m)?)?;
+    m.add_function(wrap_pyfunction!(load_cards_file, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_cards, m)?)?;
+    m.add_function(wrap_pyfunction!(load_monsters, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_monsters, m)?)?;
+    m.add_function(wrap_pyfunction!(load_modifiers, m)?)?;
+    m.add_function(wrap_pyfunction!(load_encounters, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_encounters, m)?)?;
     m.add_class::<ViewGameState>()?;
     m.add_class::<ViewCard>()?;
+    m.add_class::<ViewCardPreview>()?;
     m.add_class::<ViewCharacter>()?;
     m.add_class::<ViewMonster>()?;
     m.add_class::<ViewIntent>()?;
@@ -99,6 +186,8 @@ fn slai(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ViewMap>()?;
     m.add_class::<ViewMapNode>()?;
     m.add_class::<ViewModifier>()?;
+    m.add_class::<ViewRelic>()?;
     m.add_class::<ViewEffectTemplate>()?;
+    m.add_class::<ViewTurn>()?;
     Ok(())
 }
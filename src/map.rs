@@ -2,18 +2,27 @@
 
 use rand::Rng;
 
+use crate::encounter::{ACT_FINAL, ACT_LAST, encounters_boss};
 use crate::state::{Map, MapNode};
 use crate::types::RoomType;
 
 pub const MAP_HEIGHT: usize = 15;
 pub const MAP_WIDTH: usize = 7;
+pub const TREASURE_FLOOR_Y: usize = 8;
 
 const PATH_DENSITY: usize = 6;
 const ANCESTOR_GAP_MIN: usize = 3;
 const ANCESTOR_GAP_MAX: usize = 5;
 const FACTOR_NUM_REST_SITE: f32 = 0.25;
+const FACTOR_NUM_ELITE: f32 = 0.08;
+const FACTOR_NUM_ELITE_A1: f32 = 1.6;
+const ELITE_FLOOR_MIN: usize = 5;
 
-pub fn generate_map(rng: &mut impl Rng) -> Map {
+// Act 4 is a fixed corridor: rest site, then the Spire Shield and Spear elite
+const ACT_4_HEIGHT: usize = 2;
+const ACT_4_X: usize = MAP_WIDTH / 2;
+
+pub fn generate_map(act: u8, ascension: u8, rng: &mut impl Rng) -> Map {
     let mut nodes = initialize_nodes();
 
     let mut x_source_first: Option<usize> = None;
@@ -34,6 +43,7 @@ pub fn generate_map(rng: &mut impl Rng) -> Map {
                 x: x_source,
                 room_type: RoomType::CombatMonster, // placeholder
                 x_next: Vec::new(),
+                burning: false,
             });
         }
 
@@ -50,14 +60,15 @@ pub fn generate_map(rng: &mut impl Rng) -> Map {
                     x: x_target,
                     room_type: RoomType::CombatMonster,
                     x_next: Vec::new(),
+                    burning: false,
                 });
             }
 
             // Add edge
-            if let Some(ref mut src) = nodes[y_source][x_source] {
-                if !src.x_next.contains(&x_target) {
-                    src.x_next.push(x_target);
-                }
+            if let Some(ref mut src) = nodes[y_source][x_source]
+                && !src.x_next.contains(&x_target)
+            {
+                src.x_next.push(x_target);
             }
 
             y_source = y_target;
@@ -66,13 +77,59 @@ pub fn generate_map(rng: &mut impl Rng) -> Map {
     }
 
     trim_redundant_first_row_edges(&mut nodes);
-    assign_room_types(&mut nodes, rng);
+    assign_room_types(&mut nodes, ascension, rng);
+    assign_burning_elite(&mut nodes, rng);
+
+    let bosses = encounters_boss(act);
+    let boss = bosses[rng.random_range(0..bosses.len())];
+
+    // A20: a second, different boss right after the last act's boss (a repeat if the pool has one)
+    let boss_second = (act == ACT_LAST && ascension >= 20).then(|| {
+        let others: Vec<_> = bosses.iter().copied().filter(|&b| b != boss).collect();
+        if others.is_empty() {
+            boss
+        } else {
+            others[rng.random_range(0..others.len())]
+        }
+    });
 
     Map {
         nodes,
         active_y: None,
         active_x: None,
         boss_room_y: MAP_HEIGHT,
+        boss,
+        boss_second,
+    }
+}
+
+pub fn generate_map_act_4() -> Map {
+    let mut nodes: Vec<Vec<Option<MapNode>>> = (0..ACT_4_HEIGHT)
+        .map(|_| (0..MAP_WIDTH).map(|_| None).collect())
+        .collect();
+
+    nodes[0][ACT_4_X] = Some(MapNode {
+        y: 0,
+        x: ACT_4_X,
+        room_type: RoomType::RestSite,
+        x_next: vec![ACT_4_X],
+        burning: false,
+    });
+    nodes[1][ACT_4_X] = Some(MapNode {
+        y: 1,
+        x: ACT_4_X,
+        room_type: RoomType::CombatElite,
+        x_next: Vec::new(),
+        burning: false,
+    });
+
+    Map {
+        nodes,
+        active_y: None,
+        active_x: None,
+        boss_room_y: ACT_4_HEIGHT,
+        boss: encounters_boss(ACT_FINAL)[0],
+        boss_second: None,
     }
 }
 
@@ -165,11 +222,9 @@ fn get_node_parents(y: usize, x: usize, nodes: &[Vec<Option<MapNode>>]) -> Vec<(
     }
     let y_parent = y - 1;
     let mut parents = Vec::new();
-    for node in &nodes[y_parent] {
-        if let Some(n) = node {
-            if n.x_next.contains(&x) {
-                parents.push((n.y, n.x));
-            }
+    for n in nodes[y_parent].iter().flatten() {
+        if n.x_next.contains(&x) {
+            parents.push((n.y, n.x));
         }
     }
     parents
@@ -213,12 +268,12 @@ fn get_common_ancestor(
     None
 }
 
-fn trim_redundant_first_row_edges(nodes: &mut Vec<Vec<Option<MapNode>>>) {
+fn trim_redundant_first_row_edges(nodes: &mut [Vec<Option<MapNode>>]) {
     let mut x_seen = Vec::new();
     let mut x_remove = Vec::new();
 
-    for x_source in 0..MAP_WIDTH {
-        if let Some(ref mut node) = nodes[0][x_source] {
+    for (x_source, slot) in nodes[0].iter_mut().enumerate() {
+        if let Some(node) = slot {
             node.x_next.retain(|x| {
                 if x_seen.contains(x) {
                     false
@@ -238,24 +293,27 @@ fn trim_redundant_first_row_edges(nodes: &mut Vec<Vec<Option<MapNode>>>) {
     }
 }
 
-fn assign_room_types(nodes: &mut Vec<Vec<Option<MapNode>>>, rng: &mut impl Rng) {
+fn assign_room_types(nodes: &mut [Vec<Option<MapNode>>], ascension: u8, rng: &mut impl Rng) {
     // Collect all node positions
     let mut positions: Vec<(usize, usize)> = Vec::new();
     for row in nodes.iter() {
-        for node in row.iter() {
-            if let Some(n) = node {
-                positions.push((n.y, n.x));
-            }
+        for n in row.iter().flatten() {
+            positions.push((n.y, n.x));
         }
     }
 
     let num_nodes = positions.len();
     let num_rest = (FACTOR_NUM_REST_SITE * num_nodes as f32) as usize;
+    let factor_elite = if ascension >= 1 { FACTOR_NUM_ELITE * FACTOR_NUM_ELITE_A1 } else { FACTOR_NUM_ELITE };
+    let num_elite = (factor_elite * num_nodes as f32) as usize;
 
     let mut types = vec![RoomType::CombatMonster; num_nodes];
     for t in types.iter_mut().take(num_rest) {
         *t = RoomType::RestSite;
     }
+    for t in types.iter_mut().skip(num_rest).take(num_elite) {
+        *t = RoomType::CombatElite;
+    }
 
     // Shuffle
     for i in (1..types.len()).rev() {
@@ -265,14 +323,35 @@ fn assign_room_types(nodes: &mut Vec<Vec<Option<MapNode>>>, rng: &mut impl Rng)
 
     for (i, (y, x)) in positions.iter().enumerate() {
         if let Some(node) = &mut nodes[*y][*x] {
-            node.room_type = types[i];
+            // Elites never appear in the first floors
+            node.room_type = if types[i] == RoomType::CombatElite && *y < ELITE_FLOOR_MIN {
+                RoomType::CombatMonster
+            } else {
+                types[i]
+            };
         }
     }
 
+    // Mid-act floor is all treasure rooms
+    for n in nodes[TREASURE_FLOOR_Y].iter_mut().flatten() {
+        n.room_type = RoomType::Treasure;
+    }
+
     // Last floor is all rest sites
-    for node in &mut nodes[MAP_HEIGHT - 1] {
-        if let Some(n) = node {
-            n.room_type = RoomType::RestSite;
-        }
+    for n in nodes[MAP_HEIGHT - 1].iter_mut().flatten() {
+        n.room_type = RoomType::RestSite;
+    }
+}
+
+/// Mark one elite as burning; beating it awards the emerald key.
+fn assign_burning_elite(nodes: &mut [Vec<Option<MapNode>>], rng: &mut impl Rng) {
+    let mut elites: Vec<&mut MapNode> = nodes
+        .iter_mut()
+        .flat_map(|row| row.iter_mut().flatten())
+        .filter(|n| n.room_type == RoomType::CombatElite)
+        .collect();
+    if !elites.is_empty() {
+        let i = rng.random_range(0..elites.len());
+        elites[i].burning = true;
     }
 }
@@ -1,55 +1,201 @@
-// Modifier system: fixed-size arrays + bitmask, all free functions.
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(u8)]
-pub enum ModifierKind {
-    Accuracy = 0,
-    AfterImage,
-    Blur,
-    Burst,
-    Dexterity,
-    DoubleDamage,
-    InfiniteBlades,
-    ModeShift,
-    NextTurnBlock,
-    NextTurnEnergy,
-    Phantasmal,
-    Ritual,
-    SharpHide,
-    SporeCloud,
-    Strength,
-    ThousandCuts,
-    Vulnerable,
-    Weak,
-    Count, // sentinel for array sizing
-}
-
-pub const MODIFIER_COUNT: usize = ModifierKind::Count as usize;
+// Modifier system: per-actor stacks sorted by ID, all free functions.
 
+use std::fmt;
+
+use crate::effect::{DamageKind, Effect};
+use crate::monster;
+use crate::registry;
+use crate::state::{GameState, monster_ids_alive, vitals_ref};
+use crate::types::{ActorId, CardKind};
+
+/// ID of a modifier: the built-in ones below, then those registered at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModifierKind(pub u16);
+
+#[allow(non_upper_case_globals)]
 impl ModifierKind {
-    pub fn from_u8(v: u8) -> Self {
-        assert!((v as usize) < MODIFIER_COUNT, "invalid ModifierKind: {v}");
-        // SAFETY: repr(u8) and we validated the range
-        unsafe { std::mem::transmute(v) }
+    pub const Accuracy: Self = Self(0);
+    pub const AfterImage: Self = Self(1);
+    pub const Angry: Self = Self(2);
+    pub const BeatOfDeath: Self = Self(3);
+    pub const Blur: Self = Self(4);
+    pub const Burst: Self = Self(5);
+    pub const CorpseExplosion: Self = Self(6);
+    pub const CurlUp: Self = Self(7);
+    pub const Dexterity: Self = Self(8);
+    pub const DoubleDamage: Self = Self(9);
+    pub const Enrage: Self = Self(10);
+    pub const Envenom: Self = Self(11);
+    pub const Frail: Self = Self(12);
+    pub const InfiniteBlades: Self = Self(13);
+    pub const Invincible: Self = Self(14);
+    pub const Minion: Self = Self(15);
+    pub const ModeShift: Self = Self(16);
+    pub const NextTurnBlock: Self = Self(17);
+    pub const NextTurnEnergy: Self = Self(18);
+    pub const NoxiousFumes: Self = Self(19);
+    pub const Phantasmal: Self = Self(20);
+    pub const Poison: Self = Self(21);
+    pub const Ritual: Self = Self(22);
+    pub const SharpHide: Self = Self(23);
+    pub const Split: Self = Self(24);
+    pub const SporeCloud: Self = Self(25);
+    pub const StolenGold: Self = Self(26);
+    pub const Strength: Self = Self(27);
+    pub const ThousandCuts: Self = Self(28);
+    pub const Vulnerable: Self = Self(29);
+    pub const Weak: Self = Self(30);
+    pub const NextTurnDraw: Self = Self(31);
+    pub const Artifact: Self = Self(32);
+    pub const StrengthUp: Self = Self(33);
+    pub const Thorns: Self = Self(34);
+    pub const Curiosity: Self = Self(35);
+    pub const Regenerate: Self = Self(36);
+    pub const Unawakened: Self = Self(37);
+    pub const TimeWarp: Self = Self(38);
+    pub const DrawReduction: Self = Self(39);
+}
+
+pub const MODIFIER_BUILTIN: usize = 40;
+
+/// Cards the character plays before Time Warp ends their turn.
+pub const TIME_WARP_CARDS: i16 = 12;
+const TIME_WARP_STRENGTH: i16 = 2;
+
+static MODIFIER_NAMES: [&str; MODIFIER_BUILTIN] = [
+    "Accuracy",
+    "AfterImage",
+    "Angry",
+    "BeatOfDeath",
+    "Blur",
+    "Burst",
+    "CorpseExplosion",
+    "CurlUp",
+    "Dexterity",
+    "DoubleDamage",
+    "Enrage",
+    "Envenom",
+    "Frail",
+    "InfiniteBlades",
+    "Invincible",
+    "Minion",
+    "ModeShift",
+    "NextTurnBlock",
+    "NextTurnEnergy",
+    "NoxiousFumes",
+    "Phantasmal",
+    "Poison",
+    "Ritual",
+    "SharpHide",
+    "Split",
+    "SporeCloud",
+    "StolenGold",
+    "Strength",
+    "ThousandCuts",
+    "Vulnerable",
+    "Weak",
+    "NextTurnDraw",
+    "Artifact",
+    "StrengthUp",
+    "Thorns",
+    "Curiosity",
+    "Regenerate",
+    "Unawakened",
+    "TimeWarp",
+    "DrawReduction",
+];
+
+impl ModifierKind {
+    /// Matches the identifier printed by `Debug`; also finds registered modifiers.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match MODIFIER_NAMES.iter().position(|n| *n == name) {
+            Some(i) => Some(Self(i as u16)),
+            None => registry::modifier_find(name),
+        }
+    }
+
+    pub fn is_builtin(self) -> bool {
+        (self.0 as usize) < MODIFIER_BUILTIN
+    }
+}
+
+impl fmt::Debug for ModifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match MODIFIER_NAMES.get(self.0 as usize) {
+            Some(name) => f.write_str(name),
+            None => f.write_str(registry::modifier_name(*self)),
+        }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Hooks: effects queued when something happens to (or around) the owner
+// ---------------------------------------------------------------------------
+
+/// (state, owner, stacks) -> effects
+pub type Hook = fn(&GameState, ActorId, i16) -> Vec<Effect>;
+/// (state, owner, stacks, card_idx) -> effects
+pub type HookCardPlayed = fn(&GameState, ActorId, i16, usize) -> Vec<Effect>;
+/// (state, owner, stacks, health lost) -> effects; also used for attacks with the unblocked damage
+pub type HookDamageTaken = fn(&GameState, ActorId, i16, u16) -> Vec<Effect>;
+
 #[derive(Debug, Clone, Copy)]
+pub struct ModifierHooks {
+    pub on_card_play: Option<HookCardPlayed>, // before the card's own effects
+    pub on_card_played: Option<HookCardPlayed>, // after them
+    pub on_turn_start: Option<Hook>,
+    pub on_turn_end: Option<Hook>,
+    pub on_damage_taken: Option<HookDamageTaken>,
+    pub on_attacked: Option<HookDamageTaken>,
+    pub on_death: Option<Hook>,
+}
+
+impl PartialEq for ModifierHooks {
+    // By address: hooks merged into one address share their code, so equal hooks behave alike
+    fn eq(&self, other: &Self) -> bool {
+        let addrs = |h: &Self| {
+            [
+                h.on_card_play.map(|f| f as usize),
+                h.on_card_played.map(|f| f as usize),
+                h.on_turn_start.map(|f| f as usize),
+                h.on_turn_end.map(|f| f as usize),
+                h.on_damage_taken.map(|f| f as usize),
+                h.on_attacked.map(|f| f as usize),
+                h.on_death.map(|f| f as usize),
+            ]
+        };
+        addrs(self) == addrs(other)
+    }
+}
+
+pub const HOOKS_NONE: ModifierHooks = ModifierHooks {
+    on_card_play: None,
+    on_card_played: None,
+    on_turn_start: None,
+    on_turn_end: None,
+    on_damage_taken: None,
+    on_attacked: None,
+    on_death: None,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Modifier {
     pub kind: ModifierKind,
     pub is_buff: bool,
     pub stacks_duration: bool,
     pub min_stacks: i16,
     pub max_stacks: i16,
+    pub hooks: ModifierHooks,
 }
 
-static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
+static MODIFIERS: [Modifier; MODIFIER_BUILTIN] = [
     Modifier {
         kind: ModifierKind::Accuracy,
         is_buff: true,
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::AfterImage,
@@ -57,6 +203,32 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_play: Some(after_image_card_played),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Angry,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_damage_taken: Some(angry_damage_taken),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::BeatOfDeath,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_played: Some(beat_of_death_card_played),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Blur,
@@ -64,6 +236,10 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: true,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(blur_turn_start),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Burst,
@@ -71,6 +247,33 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_played: Some(burst_card_played),
+            on_turn_end: Some(burst_turn_end),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::CorpseExplosion,
+        is_buff: false,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_death: Some(corpse_explosion_death),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::CurlUp,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_attacked: Some(curl_up_attacked),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Dexterity,
@@ -78,6 +281,7 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: -999,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::DoubleDamage,
@@ -85,6 +289,34 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: true,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
+    },
+    Modifier {
+        kind: ModifierKind::Enrage,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_played: Some(enrage_card_played),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Envenom,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: HOOKS_NONE,
+    },
+    Modifier {
+        kind: ModifierKind::Frail,
+        is_buff: false,
+        stacks_duration: true,
+        min_stacks: 0,
+        max_stacks: 999,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::InfiniteBlades,
@@ -92,6 +324,29 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(infinite_blades_turn_start),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Invincible,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 0,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(invincible_turn_start),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Minion,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 1,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::ModeShift,
@@ -99,6 +354,10 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_damage_taken: Some(mode_shift_damage_taken),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::NextTurnBlock,
@@ -106,6 +365,10 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(next_turn_block_turn_start),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::NextTurnEnergy,
@@ -113,6 +376,21 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(next_turn_energy_turn_start),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::NoxiousFumes,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(noxious_fumes_turn_start),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Phantasmal,
@@ -120,6 +398,21 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: true,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(phantasmal_turn_start),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Poison,
+        is_buff: false,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(poison_turn_start),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Ritual,
@@ -127,6 +420,10 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_end: Some(ritual_turn_end),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::SharpHide,
@@ -134,6 +431,21 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_play: Some(sharp_hide_card_played),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Split,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 1,
+        hooks: ModifierHooks {
+            on_damage_taken: Some(split_damage_taken),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::SporeCloud,
@@ -141,6 +453,21 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_death: Some(spore_cloud_death),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::StolenGold,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_death: Some(stolen_gold_death),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Strength,
@@ -148,6 +475,7 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: -999,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::ThousandCuts,
@@ -155,6 +483,10 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: false,
         min_stacks: 1,
         max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_play: Some(thousand_cuts_card_played),
+            ..HOOKS_NONE
+        },
     },
     Modifier {
         kind: ModifierKind::Vulnerable,
@@ -162,6 +494,7 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: true,
         min_stacks: 0,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
     },
     Modifier {
         kind: ModifierKind::Weak,
@@ -169,77 +502,574 @@ static MODIFIERS: [Modifier; MODIFIER_COUNT] = [
         stacks_duration: true,
         min_stacks: 0,
         max_stacks: 999,
+        hooks: HOOKS_NONE,
+    },
+    Modifier {
+        kind: ModifierKind::NextTurnDraw,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(next_turn_draw_turn_start),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Artifact,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: HOOKS_NONE, // checked when a debuff is applied
+    },
+    Modifier {
+        kind: ModifierKind::StrengthUp,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_end: Some(strength_up_turn_end),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Thorns,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_attacked: Some(thorns_attacked),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Curiosity,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_card_played: Some(curiosity_card_played),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Regenerate,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_end: Some(regenerate_turn_end),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::Unawakened,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 1,
+        hooks: HOOKS_NONE, // checked on death
+    },
+    Modifier {
+        kind: ModifierKind::TimeWarp,
+        is_buff: true,
+        stacks_duration: false,
+        min_stacks: 0, // cards played so far
+        max_stacks: TIME_WARP_CARDS,
+        hooks: ModifierHooks {
+            on_card_played: Some(time_warp_card_played),
+            ..HOOKS_NONE
+        },
+    },
+    Modifier {
+        kind: ModifierKind::DrawReduction,
+        is_buff: false,
+        stacks_duration: false,
+        min_stacks: 1,
+        max_stacks: 999,
+        hooks: ModifierHooks {
+            on_turn_start: Some(draw_reduction_turn_start),
+            ..HOOKS_NONE
+        },
     },
 ];
 
 pub fn modifier_def(kind: ModifierKind) -> &'static Modifier {
-    &MODIFIERS[kind as usize]
+    match MODIFIERS.get(kind.0 as usize) {
+        Some(def) => def,
+        None => registry::modifier_def(kind),
+    }
+}
+
+/// One active modifier on an actor.
+#[derive(Debug, Clone, Copy)]
+pub struct ModifierStack {
+    pub kind: ModifierKind,
+    pub stacks: i16,
+    pub is_new: bool,
 }
 
-#[derive(Debug, Clone)]
+/// Active modifiers, sorted by ID so iteration follows table order.
+#[derive(Debug, Clone, Default)]
 pub struct Modifiers {
-    pub stacks: [i16; MODIFIER_COUNT],
-    pub is_new: [bool; MODIFIER_COUNT],
-    pub active: u32,
+    pub active: Vec<ModifierStack>,
 }
 
 pub fn modifiers_new() -> Modifiers {
-    Modifiers {
-        stacks: [0; MODIFIER_COUNT],
-        is_new: [false; MODIFIER_COUNT],
-        active: 0,
-    }
+    Modifiers { active: Vec::new() }
+}
+
+fn modifier_find(mods: &Modifiers, kind: ModifierKind) -> Result<usize, usize> {
+    mods.active.binary_search_by_key(&kind.0, |m| m.kind.0)
+}
+
+/// Active modifier kinds in table order.
+pub fn modifier_kinds(mods: &Modifiers) -> impl Iterator<Item = ModifierKind> + '_ {
+    mods.active.iter().map(|m| m.kind)
 }
 
 pub fn modifier_stacks(mods: &Modifiers, kind: ModifierKind) -> i16 {
-    mods.stacks[kind as usize]
+    match modifier_find(mods, kind) {
+        Ok(i) => mods.active[i].stacks,
+        Err(_) => 0,
+    }
 }
 
 pub fn modifier_has(mods: &Modifiers, kind: ModifierKind) -> bool {
-    mods.active & (1 << kind as u32) != 0
+    modifier_find(mods, kind).is_ok()
+}
+
+/// Whether the modifier was gained since the last `modifier_set_not_new`.
+pub fn modifier_is_new(mods: &Modifiers, kind: ModifierKind) -> bool {
+    match modifier_find(mods, kind) {
+        Ok(i) => mods.active[i].is_new,
+        Err(_) => false,
+    }
 }
 
 pub fn modifier_apply(mods: &mut Modifiers, kind: ModifierKind, stacks: i16) {
     let cfg = modifier_def(kind);
-    let idx = kind as usize;
-    if modifier_has(mods, kind) {
-        mods.stacks[idx] = (mods.stacks[idx] + stacks).clamp(cfg.min_stacks, cfg.max_stacks);
-    } else {
-        mods.stacks[idx] = stacks.clamp(cfg.min_stacks, cfg.max_stacks);
-        mods.is_new[idx] = true;
-        mods.active |= 1 << kind as u32;
+    match modifier_find(mods, kind) {
+        Ok(i) => {
+            let m = &mut mods.active[i];
+            m.stacks = m.stacks.saturating_add(stacks).clamp(cfg.min_stacks, cfg.max_stacks);
+        }
+        Err(i) => mods.active.insert(
+            i,
+            ModifierStack {
+                kind,
+                stacks: stacks.clamp(cfg.min_stacks, cfg.max_stacks),
+                is_new: true,
+            },
+        ),
+    }
+}
+
+/// Overwrites the stacks of an active modifier, unclamped; removes it once
+/// below its minimum.
+pub fn modifier_set_stacks(mods: &mut Modifiers, kind: ModifierKind, stacks: i16) {
+    if let Ok(i) = modifier_find(mods, kind) {
+        if stacks < modifier_def(kind).min_stacks {
+            mods.active.remove(i);
+        } else {
+            mods.active[i].stacks = stacks;
+        }
     }
 }
 
 pub fn modifier_remove(mods: &mut Modifiers, kind: ModifierKind) {
-    let idx = kind as usize;
-    mods.stacks[idx] = 0;
-    mods.is_new[idx] = false;
-    mods.active &= !(1 << kind as u32);
+    if let Ok(i) = modifier_find(mods, kind) {
+        mods.active.remove(i);
+    }
 }
 
 pub fn modifier_tick(mods: &mut Modifiers) {
-    let mut bits = mods.active;
-    while bits != 0 {
-        let idx = bits.trailing_zeros() as usize;
-        bits &= bits - 1;
-        let kind = ModifierKind::from_u8(idx as u8);
-        let cfg = modifier_def(kind);
-        if cfg.stacks_duration && !mods.is_new[idx] {
-            mods.stacks[idx] -= 1;
-            if mods.stacks[idx] < cfg.min_stacks {
-                modifier_remove(mods, kind);
-            }
+    mods.active.retain_mut(|m| {
+        let cfg = modifier_def(m.kind);
+        if !cfg.stacks_duration || m.is_new {
+            return true;
         }
+        m.stacks -= 1;
+        m.stacks >= cfg.min_stacks
+    });
+}
+
+/// Marks one modifier as old, so it ticks and fires from this turn on.
+pub fn modifier_set_old(mods: &mut Modifiers, kind: ModifierKind) {
+    if let Ok(i) = modifier_find(mods, kind) {
+        mods.active[i].is_new = false;
     }
 }
 
+/// Modifiers that aren't buffs, and buffs lowered below zero (Strength, Dexterity).
+pub fn modifier_debuffs(mods: &Modifiers) -> impl Iterator<Item = ModifierKind> + '_ {
+    mods.active
+        .iter()
+        .filter(|m| !modifier_def(m.kind).is_buff || m.stacks < 0)
+        .map(|m| m.kind)
+}
+
 pub fn modifier_set_not_new(mods: &mut Modifiers) {
-    mods.is_new = [false; MODIFIER_COUNT];
+    for m in &mut mods.active {
+        m.is_new = false;
+    }
 }
 
 pub fn modifier_clear(mods: &mut Modifiers) {
-    mods.stacks = [0; MODIFIER_COUNT];
-    mods.is_new = [false; MODIFIER_COUNT];
-    mods.active = 0;
+    mods.active.clear();
+}
+
+// ---------------------------------------------------------------------------
+// Hook implementations
+// ---------------------------------------------------------------------------
+
+fn monster_ids(state: &GameState) -> impl Iterator<Item = ActorId> + '_ {
+    monster_ids_alive(state).map(ActorId::Monster)
+}
+
+fn after_image_card_played(_: &GameState, owner: ActorId, stacks: i16, _: usize) -> Vec<Effect> {
+    vec![Effect::BlockGain {
+        target: owner,
+        amount: stacks as u16,
+        from_card: false,
+    }]
+}
+
+fn beat_of_death_card_played(_: &GameState, _: ActorId, stacks: i16, _: usize) -> Vec<Effect> {
+    vec![Effect::DamageDeal {
+        target: ActorId::Character,
+        amount: stacks as u16,
+    }]
+}
+
+fn burst_card_played(state: &GameState, owner: ActorId, _: i16, card_idx: usize) -> Vec<Effect> {
+    // Skills are played a second time
+    if state.combat_cards[card_idx].kind != CardKind::Skill {
+        return Vec::new();
+    }
+    vec![
+        Effect::CardReplay {
+            card_idx,
+            x: state.card_x,
+        },
+        Effect::ModifierGain {
+            target: owner,
+            kind: ModifierKind::Burst,
+            stacks: -1,
+        },
+    ]
+}
+
+fn enrage_card_played(state: &GameState, owner: ActorId, stacks: i16, card_idx: usize) -> Vec<Effect> {
+    if state.combat_cards[card_idx].kind != CardKind::Skill {
+        return Vec::new();
+    }
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::Strength,
+        stacks,
+    }]
+}
+
+fn sharp_hide_card_played(state: &GameState, _: ActorId, stacks: i16, card_idx: usize) -> Vec<Effect> {
+    if state.combat_cards[card_idx].kind != CardKind::Attack {
+        return Vec::new();
+    }
+    vec![Effect::DamageDeal {
+        target: ActorId::Character,
+        amount: stacks as u16,
+    }]
+}
+
+fn thousand_cuts_card_played(state: &GameState, owner: ActorId, stacks: i16, _: usize) -> Vec<Effect> {
+    monster_ids(state)
+        .map(|target| Effect::DamagePhysical {
+            source: owner,
+            target,
+            base: stacks as u16,
+            kind: DamageKind::Thorns,
+            card: None,
+        })
+        .collect()
+}
+
+fn blur_turn_start(state: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    // Turn-start hooks run while process_turn_start builds its effects, before
+    // its queued BlockSet 0 resolves, so this reads last turn's block and gains
+    // it back after the reset. Reading it later would see 0.
+    vec![Effect::BlockGain {
+        target: owner,
+        amount: vitals_ref(state, owner).block,
+        from_card: false,
+    }]
+}
+
+fn infinite_blades_turn_start(_: &GameState, _: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![Effect::AddShivs {
+        count: stacks as u8,
+    }]
+}
+
+fn invincible_turn_start(state: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    vec![Effect::ModifierSet {
+        target: owner,
+        kind: ModifierKind::Invincible,
+        stacks: monster::heart_invincible_cap(state.ascension),
+    }]
+}
+
+fn next_turn_block_turn_start(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![
+        Effect::BlockGain {
+            target: owner,
+            amount: stacks as u16,
+            from_card: false,
+        },
+        Effect::ModifierRemove {
+            target: owner,
+            kind: ModifierKind::NextTurnBlock,
+        },
+    ]
+}
+
+fn next_turn_draw_turn_start(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![
+        Effect::CardDraw { count: stacks as u8 },
+        Effect::ModifierRemove {
+            target: owner,
+            kind: ModifierKind::NextTurnDraw,
+        },
+    ]
+}
+
+fn next_turn_energy_turn_start(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![
+        Effect::EnergyGain {
+            amount: stacks as u8,
+        },
+        Effect::ModifierRemove {
+            target: owner,
+            kind: ModifierKind::NextTurnEnergy,
+        },
+    ]
+}
+
+fn noxious_fumes_turn_start(state: &GameState, _: ActorId, stacks: i16) -> Vec<Effect> {
+    monster_ids(state)
+        .map(|target| Effect::ModifierGain {
+            target,
+            kind: ModifierKind::Poison,
+            stacks,
+        })
+        .collect()
+}
+
+fn phantasmal_turn_start(_: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::DoubleDamage,
+        stacks: 1,
+    }]
+}
+
+fn poison_turn_start(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![
+        Effect::HealthLoss {
+            target: owner,
+            amount: stacks as u16,
+        },
+        Effect::ModifierGain {
+            target: owner,
+            kind: ModifierKind::Poison,
+            stacks: -1,
+        },
+    ]
+}
+
+fn burst_turn_end(_: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    vec![Effect::ModifierRemove {
+        target: owner,
+        kind: ModifierKind::Burst,
+    }]
+}
+
+fn ritual_turn_end(state: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    // No Strength on the turn Ritual was gained
+    if modifier_is_new(&vitals_ref(state, owner).modifiers, ModifierKind::Ritual) {
+        return Vec::new();
+    }
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::Strength,
+        stacks,
+    }]
+}
+
+/// Like Ritual, but also on the turn it was gained.
+fn strength_up_turn_end(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::Strength,
+        stacks,
+    }]
+}
+
+fn thorns_attacked(_: &GameState, owner: ActorId, stacks: i16, _: u16) -> Vec<Effect> {
+    // Only monsters have Thorns, and only the character attacks them
+    if owner == ActorId::Character {
+        return Vec::new();
+    }
+    vec![Effect::DamageDeal {
+        target: ActorId::Character,
+        amount: stacks as u16,
+    }]
+}
+
+fn curiosity_card_played(state: &GameState, owner: ActorId, stacks: i16, card_idx: usize) -> Vec<Effect> {
+    if state.combat_cards[card_idx].kind != CardKind::Power {
+        return Vec::new();
+    }
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::Strength,
+        stacks,
+    }]
+}
+
+fn regenerate_turn_end(_: &GameState, owner: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![Effect::HealthGain {
+        target: owner,
+        amount: stacks as u16,
+    }]
+}
+
+fn time_warp_card_played(_: &GameState, owner: ActorId, stacks: i16, _: usize) -> Vec<Effect> {
+    if stacks + 1 < TIME_WARP_CARDS {
+        return vec![Effect::ModifierSet {
+            target: owner,
+            kind: ModifierKind::TimeWarp,
+            stacks: stacks + 1,
+        }];
+    }
+    vec![
+        Effect::ModifierSet {
+            target: owner,
+            kind: ModifierKind::TimeWarp,
+            stacks: 0,
+        },
+        Effect::ModifierGain {
+            target: owner,
+            kind: ModifierKind::Strength,
+            stacks: TIME_WARP_STRENGTH,
+        },
+        Effect::TurnEnd {
+            actor: ActorId::Character,
+        },
+    ]
+}
+
+/// The smaller draw is read when the turn starts; this only removes it.
+fn draw_reduction_turn_start(_: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    vec![Effect::ModifierRemove {
+        target: owner,
+        kind: ModifierKind::DrawReduction,
+    }]
+}
+
+fn mode_shift_damage_taken(_: &GameState, owner: ActorId, stacks: i16, amount: u16) -> Vec<Effect> {
+    let remaining = stacks - amount as i16;
+    if remaining >= modifier_def(ModifierKind::ModeShift).min_stacks {
+        return vec![Effect::ModifierSet {
+            target: owner,
+            kind: ModifierKind::ModeShift,
+            stacks: remaining,
+        }];
+    }
+
+    // Shifted: drop the modifier and pick the defensive move
+    let mut effects = vec![Effect::ModifierRemove {
+        target: owner,
+        kind: ModifierKind::ModeShift,
+    }];
+    if let ActorId::Monster(i) = owner {
+        effects.push(Effect::MonsterMoveUpdate { monster_idx: i });
+    }
+    effects
+}
+
+fn angry_damage_taken(_: &GameState, owner: ActorId, stacks: i16, _: u16) -> Vec<Effect> {
+    vec![Effect::ModifierGain {
+        target: owner,
+        kind: ModifierKind::Strength,
+        stacks,
+    }]
+}
+
+fn curl_up_attacked(state: &GameState, owner: ActorId, stacks: i16, amount: u16) -> Vec<Effect> {
+    // Only a hit that hurts without killing makes it curl up, once
+    if amount == 0 || amount >= vitals_ref(state, owner).health {
+        return Vec::new();
+    }
+    vec![
+        Effect::ModifierRemove {
+            target: owner,
+            kind: ModifierKind::CurlUp,
+        },
+        Effect::BlockGain {
+            target: owner,
+            amount: stacks as u16,
+            from_card: false,
+        },
+    ]
+}
+
+fn split_damage_taken(state: &GameState, owner: ActorId, _: i16, _: u16) -> Vec<Effect> {
+    let vitals = vitals_ref(state, owner);
+    if vitals.health * 2 > vitals.health_max {
+        return Vec::new();
+    }
+
+    // At half health the next move becomes Split, replacing the current intent
+    let mut effects = vec![Effect::ModifierRemove {
+        target: owner,
+        kind: ModifierKind::Split,
+    }];
+    if let ActorId::Monster(i) = owner {
+        effects.push(Effect::MonsterMoveUpdate { monster_idx: i });
+    }
+    effects
+}
+
+fn corpse_explosion_death(state: &GameState, owner: ActorId, _: i16) -> Vec<Effect> {
+    let ActorId::Monster(dead) = owner else {
+        return Vec::new();
+    };
+    let amount = vitals_ref(state, owner).health_max;
+
+    monster_ids_alive(state)
+        .filter(|&i| i != dead)
+        .map(|i| Effect::DamageDeal {
+            target: ActorId::Monster(i),
+            amount,
+        })
+        .collect()
+}
+
+fn spore_cloud_death(_: &GameState, _: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![Effect::ModifierGain {
+        target: ActorId::Character,
+        kind: ModifierKind::Vulnerable,
+        stacks,
+    }]
+}
+
+fn stolen_gold_death(_: &GameState, _: ActorId, stacks: i16) -> Vec<Effect> {
+    vec![Effect::GoldGain {
+        amount: stacks as u16,
+    }]
 }
@@ -2,8 +2,9 @@
 
 use rand::Rng;
 
-use crate::effect::{EffectTemplate, TargetKind};
-use crate::modifier::{ModifierKind, modifier_apply, modifier_has};
+use crate::registry;
+use crate::effect::{EffectTemplate, PileKind, TargetKind};
+use crate::modifier::{ModifierKind, modifier_apply, modifier_has, modifier_set_old};
 use crate::state::{Intent, Monster, Move, vitals_new};
 use crate::types::*;
 
@@ -43,9 +44,70 @@ static FB_GROW_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: Mo
 static FB_GROW_4: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 4, target: TargetKind::Source }];
 static FB_GROW_5: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 5, target: TargetKind::Source }];
 
-// Louse Green
-static LG_GROW_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::Source }];
-static LG_GROW_4: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 4, target: TargetKind::Source }];
+// Louse (red grows, green spits web); the bite damage is rolled at spawn
+static LOUSE_BITE_5: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character }];
+static LOUSE_BITE_6: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character }];
+static LOUSE_BITE_7: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character }];
+static LOUSE_BITE_8: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character }];
+static LOUSE_GROW_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::Source }];
+static LOUSE_GROW_4: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 4, target: TargetKind::Source }];
+static LOUSE_SPIT_WEB: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character }];
+
+// Looter
+static LT_MUG_10_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static LT_MUG_11_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static LT_MUG_11_20: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 20 },
+];
+static LT_LUNGE_12_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static LT_LUNGE_14_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static LT_LUNGE_14_20: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 20 },
+];
+static LT_SMOKE_BOMB: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 6, target: TargetKind::Source }];
+static LT_ESCAPE: [EffectTemplate; 1] = [EffectTemplate::Escape];
+
+// Mugger: the Looter's moves with bigger numbers
+static MG_MUG_10_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static MG_MUG_11_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static MG_MUG_11_20: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 20 },
+];
+static MG_LUNGE_16_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static MG_LUNGE_18_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 15 },
+];
+static MG_LUNGE_18_20: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character },
+    EffectTemplate::GoldSteal { amount: 20 },
+];
+static MG_SMOKE_BOMB_11: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 11, target: TargetKind::Source }];
+static MG_SMOKE_BOMB_17: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 17, target: TargetKind::Source }];
 
 // The Guardian
 static TG_CHARGING_UP: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 9, target: TargetKind::Source }];
@@ -72,6 +134,612 @@ static TG_TWIN_SLAM: [EffectTemplate; 4] = [
     EffectTemplate::ModifierRemove { kind: ModifierKind::SharpHide, target: TargetKind::Source },
 ];
 
+// Hexaghost
+static HEX_DIVIDER: [EffectTemplate; 6] = [
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysicalPerHealth { divisor: 12, target: TargetKind::Character },
+];
+static HEX_SEAR_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Discard },
+];
+static HEX_SEAR_2: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 2, pile: PileKind::Discard },
+];
+// After Inferno every Burn is upgraded, so upgrading all of them again adds Burn+
+static HEX_SEAR_PLUS_1: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Discard },
+    EffectTemplate::CardUpgradeAll { name: CardName::Burn },
+];
+static HEX_SEAR_PLUS_2: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 2, pile: PileKind::Discard },
+    EffectTemplate::CardUpgradeAll { name: CardName::Burn },
+];
+static HEX_TACKLE_5: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+];
+static HEX_TACKLE_6: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+];
+static HEX_INFLAME_2: [EffectTemplate; 2] = [
+    EffectTemplate::BlockGain { amount: 12, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 2, target: TargetKind::Source },
+];
+static HEX_INFLAME_3: [EffectTemplate; 2] = [
+    EffectTemplate::BlockGain { amount: 12, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::Source },
+];
+static HEX_INFERNO_2: [EffectTemplate; 7] = [
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::CardUpgradeAll { name: CardName::Burn },
+];
+static HEX_INFERNO_3: [EffectTemplate; 7] = [
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character },
+    EffectTemplate::CardUpgradeAll { name: CardName::Burn },
+];
+
+// Byrd
+static BYRD_PECK_5: [EffectTemplate; 5] = [
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+];
+static BYRD_PECK_6: [EffectTemplate; 6] = [
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 1, target: TargetKind::Character },
+];
+static BYRD_CAW: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 1, target: TargetKind::Source }];
+static BYRD_SWOOP_12: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character }];
+static BYRD_SWOOP_14: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character }];
+
+// Chosen
+static CH_POKE_5: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+];
+static CH_POKE_6: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+];
+static CH_ZAP_18: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character }];
+static CH_ZAP_21: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 21, target: TargetKind::Character }];
+static CH_DEBILITATE_10: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static CH_DEBILITATE_12: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static CH_DRAIN: [EffectTemplate; 2] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 3, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::Source },
+];
+
+// The Champ
+static TC_DEFENSIVE_STANCE_15: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 15, target: TargetKind::Source }];
+static TC_DEFENSIVE_STANCE_18: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 18, target: TargetKind::Source }];
+static TC_DEFENSIVE_STANCE_20: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 20, target: TargetKind::Source }];
+static TC_FACE_SLAP_12: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static TC_FACE_SLAP_14: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static TC_HEAVY_SLASH_16: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character }];
+static TC_HEAVY_SLASH_18: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character }];
+static TC_GLOAT_2: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 2, target: TargetKind::Source }];
+static TC_GLOAT_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::Source }];
+static TC_GLOAT_4: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 4, target: TargetKind::Source }];
+static TC_TAUNT: [EffectTemplate; 2] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static TC_EXECUTE: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+];
+static TC_ANGER_6: [EffectTemplate; 4] = [
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Weak, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Vulnerable, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Frail, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 6, target: TargetKind::Source },
+];
+static TC_ANGER_9: [EffectTemplate; 4] = [
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Weak, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Vulnerable, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Frail, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 9, target: TargetKind::Source },
+];
+static TC_ANGER_12: [EffectTemplate; 4] = [
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Weak, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Vulnerable, target: TargetKind::Source },
+    EffectTemplate::ModifierRemove { kind: ModifierKind::Frail, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 12, target: TargetKind::Source },
+];
+
+// Orb Walker
+static OW_LASER_10: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Discard },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Draw },
+];
+static OW_LASER_11: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Discard },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Draw },
+];
+static OW_CLAW_15: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 15, target: TargetKind::Character }];
+static OW_CLAW_16: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character }];
+
+// Awakened One
+static AO_SLASH: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 20, target: TargetKind::Character }];
+static AO_SOUL_STRIKE: [EffectTemplate; 4] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+];
+static AO_DARK_ECHO: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 40, target: TargetKind::Character }];
+static AO_SLUDGE: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Void, count: 1, pile: PileKind::Draw },
+];
+static AO_TACKLE: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+];
+
+// Time Eater
+static TE_REVERBERATE_7: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+];
+static TE_REVERBERATE_8: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+];
+static TE_RIPPLE: [EffectTemplate; 3] = [
+    EffectTemplate::BlockGain { amount: 20, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 1, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static TE_RIPPLE_FRAIL: [EffectTemplate; 4] = [
+    EffectTemplate::BlockGain { amount: 20, target: TargetKind::Source },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 1, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 1, target: TargetKind::Character },
+];
+static TE_HEAD_SLAM_26: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 26, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::DrawReduction, stacks: 1, target: TargetKind::Character },
+];
+static TE_HEAD_SLAM_32: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 32, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::DrawReduction, stacks: 1, target: TargetKind::Character },
+];
+static TE_HEAD_SLAM_32_SLIMED: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 32, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::DrawReduction, stacks: 1, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 2, pile: PileKind::Discard },
+];
+static TE_HASTE: [EffectTemplate; 1] = [EffectTemplate::Recover { percent: 50 }];
+static TE_HASTE_32: [EffectTemplate; 2] = [
+    EffectTemplate::Recover { percent: 50 },
+    EffectTemplate::BlockGain { amount: 32, target: TargetKind::Source },
+];
+
+// Writhing Mass
+static WM_STRONG_HIT_32: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 32, target: TargetKind::Character }];
+static WM_STRONG_HIT_38: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 38, target: TargetKind::Character }];
+static WM_MULTI_STRIKE_7: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+];
+static WM_MULTI_STRIKE_9: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+];
+static WM_FLAIL_15: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 15, target: TargetKind::Character },
+    EffectTemplate::BlockGain { amount: 16, target: TargetKind::Source },
+];
+static WM_FLAIL_16: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character },
+    EffectTemplate::BlockGain { amount: 16, target: TargetKind::Source },
+];
+static WM_WITHER_10: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static WM_WITHER_12: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+
+// Donu and Deca
+static DONU_BEAM_10: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+];
+static DONU_BEAM_12: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+];
+static DECA_BEAM_10: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Dazed, count: 2, pile: PileKind::Discard },
+];
+static DECA_BEAM_12: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Dazed, count: 2, pile: PileKind::Discard },
+];
+// Shapes
+static RP_BASH_11: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character }];
+static RP_BASH_13: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character }];
+static RP_REPULSE: [EffectTemplate; 1] = [EffectTemplate::CardAdd { name: CardName::Dazed, count: 2, pile: PileKind::Draw }];
+static SP_CUT_7: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character }];
+static SP_CUT_9: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character }];
+static SP_SPIKE: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Thorns, stacks: 2, target: TargetKind::Source }];
+static EX_SLAM_9: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character }];
+static EX_SLAM_11: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character }];
+static EX_EXPLODE: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 30, target: TargetKind::Character },
+    EffectTemplate::Suicide,
+];
+
+static DONU_CIRCLE_OF_POWER: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::AllMonsters }];
+static DECA_SQUARE_OF_PROTECTION: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 16, target: TargetKind::AllMonsters }];
+
+// Gremlin Nob
+static GN_BELLOW_2: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Enrage, stacks: 2, target: TargetKind::Source }];
+static GN_BELLOW_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Enrage, stacks: 3, target: TargetKind::Source }];
+static GN_RUSH_14: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character }];
+static GN_RUSH_16: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character }];
+static GN_SKULL_BASH_6: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+static GN_SKULL_BASH_8: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+
+// Blue Slaver
+static BS_STAB_12: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character }];
+static BS_STAB_13: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character }];
+static BS_RAKE_7_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static BS_RAKE_8_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static BS_RAKE_8_2: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character },
+];
+
+// Red Slaver
+static RS_STAB_13: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character }];
+static RS_STAB_14: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character }];
+static RS_SCRAPE_8_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 1, target: TargetKind::Character },
+];
+static RS_SCRAPE_9_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 1, target: TargetKind::Character },
+];
+static RS_SCRAPE_9_2: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+];
+
+// Taskmaster
+static TM_SCOURING_WHIP_1: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Wound, count: 1, pile: PileKind::Discard },
+];
+static TM_SCOURING_WHIP_2: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Wound, count: 2, pile: PileKind::Discard },
+];
+static TM_SCOURING_WHIP_3: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Wound, count: 3, pile: PileKind::Discard },
+];
+
+// Giant Head
+static GH_GLARE: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character }];
+static GH_COUNT: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character }];
+static GH_IT_IS_TIME_30: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 30, target: TargetKind::Character }];
+static GH_IT_IS_TIME_35: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 35, target: TargetKind::Character }];
+static GH_IT_IS_TIME_40: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 40, target: TargetKind::Character }];
+static GH_IT_IS_TIME_45: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 45, target: TargetKind::Character }];
+static GH_IT_IS_TIME_50: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 50, target: TargetKind::Character }];
+static GH_IT_IS_TIME_55: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 55, target: TargetKind::Character }];
+static GH_IT_IS_TIME_60: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 60, target: TargetKind::Character }];
+static GH_IT_IS_TIME_65: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 65, target: TargetKind::Character }];
+static GH_IT_IS_TIME_70: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 70, target: TargetKind::Character }];
+
+// Spire Shield
+static SSH_BASH_12: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: -1, target: TargetKind::Character },
+];
+static SSH_BASH_14: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 14, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: -1, target: TargetKind::Character },
+];
+static SSH_FORTIFY: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 30, target: TargetKind::AllMonsters }];
+static SSH_SMASH_34_34: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 34, target: TargetKind::Character },
+    EffectTemplate::BlockGain { amount: 34, target: TargetKind::Source },
+];
+static SSH_SMASH_38_38: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 38, target: TargetKind::Character },
+    EffectTemplate::BlockGain { amount: 38, target: TargetKind::Source },
+];
+static SSH_SMASH_38_99: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 38, target: TargetKind::Character },
+    EffectTemplate::BlockGain { amount: 99, target: TargetKind::Source },
+];
+
+// Spire Spear
+static SSP_BURN_STRIKE_5: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 2, pile: PileKind::Discard },
+];
+static SSP_BURN_STRIKE_6: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 2, pile: PileKind::Discard },
+];
+static SSP_BURN_STRIKE_6_DRAW: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 2, pile: PileKind::Draw },
+];
+static SSP_PIERCER: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 2, target: TargetKind::AllMonsters }];
+static SSP_SKEWER_3: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+];
+static SSP_SKEWER_4: [EffectTemplate; 4] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+];
+
+// Corrupt Heart
+static CH_HEART_DEBILITATE: [EffectTemplate; 8] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Vulnerable, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 2, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Dazed, count: 1, pile: PileKind::Draw },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 1, pile: PileKind::Draw },
+    EffectTemplate::CardAdd { name: CardName::Wound, count: 1, pile: PileKind::Draw },
+    EffectTemplate::CardAdd { name: CardName::Burn, count: 1, pile: PileKind::Draw },
+    EffectTemplate::CardAdd { name: CardName::Void, count: 1, pile: PileKind::Draw },
+];
+static CH_BLOOD_SHOTS_12: [EffectTemplate; 12] = [
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+];
+static CH_BLOOD_SHOTS_15: [EffectTemplate; 15] = [
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 2, target: TargetKind::Character },
+];
+static CH_ECHO_40: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 40, target: TargetKind::Character }];
+static CH_ECHO_45: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 45, target: TargetKind::Character }];
+static CH_HEART_BUFF: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 2, target: TargetKind::Source }];
+
+// Acid Slime
+static AS_CORROSIVE_SPIT_7: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 7, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 1, pile: PileKind::Discard },
+];
+static AS_CORROSIVE_SPIT_8: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 1, pile: PileKind::Discard },
+];
+static AS_CORROSIVE_SPIT_11: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 11, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 2, pile: PileKind::Discard },
+];
+static AS_CORROSIVE_SPIT_12: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 2, pile: PileKind::Discard },
+];
+static AS_TACKLE_3: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 3, target: TargetKind::Character }];
+static AS_TACKLE_4: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 4, target: TargetKind::Character }];
+static AS_TACKLE_10: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character }];
+static AS_TACKLE_12: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character }];
+static AS_TACKLE_16: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character }];
+static AS_TACKLE_18: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character }];
+static AS_LICK_1: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character }];
+static AS_LICK_2: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 2, target: TargetKind::Character }];
+static AS_SPLIT: [EffectTemplate; 1] = [EffectTemplate::MonsterSplit { into: &[MonsterName::AcidSlimeM, MonsterName::AcidSlimeM] }];
+
+// Spike Slime
+static SS_TACKLE_5: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character }];
+static SS_TACKLE_6: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character }];
+static SS_FLAME_TACKLE_8: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 1, pile: PileKind::Discard },
+];
+static SS_FLAME_TACKLE_10: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 1, pile: PileKind::Discard },
+];
+static SS_FLAME_TACKLE_16: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 2, pile: PileKind::Discard },
+];
+static SS_FLAME_TACKLE_18: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 18, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Slimed, count: 2, pile: PileKind::Discard },
+];
+static SS_LICK_1: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 1, target: TargetKind::Character }];
+static SS_LICK_2: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 2, target: TargetKind::Character }];
+static SS_LICK_3: [EffectTemplate; 1] = [EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 3, target: TargetKind::Character }];
+static SS_SPLIT: [EffectTemplate; 1] = [EffectTemplate::MonsterSplit { into: &[MonsterName::SpikeSlimeM, MonsterName::SpikeSlimeM] }];
+
+// Slime Boss
+static SB_GOOP_SPRAY_3: [EffectTemplate; 1] = [EffectTemplate::CardAdd { name: CardName::Slimed, count: 3, pile: PileKind::Discard }];
+static SB_GOOP_SPRAY_5: [EffectTemplate; 1] = [EffectTemplate::CardAdd { name: CardName::Slimed, count: 5, pile: PileKind::Discard }];
+static SB_SLAM_35: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 35, target: TargetKind::Character }];
+static SB_SLAM_38: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 38, target: TargetKind::Character }];
+static SB_SPLIT: [EffectTemplate; 1] = [EffectTemplate::MonsterSplit { into: &[MonsterName::AcidSlimeL, MonsterName::SpikeSlimeL] }];
+
+// Gremlins
+static MG_SCRATCH_4: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 4, target: TargetKind::Character }];
+static MG_SCRATCH_5: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character }];
+static SG_PUNCTURE_9: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character }];
+static SG_PUNCTURE_10: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 10, target: TargetKind::Character }];
+static FG_SMASH_4: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 4, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static FG_SMASH_5: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static FG_SMASH_5_FRAIL: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 5, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Frail, stacks: 1, target: TargetKind::Character },
+];
+static SHG_PROTECT_7: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 7, target: TargetKind::RandomAlly }];
+static SHG_PROTECT_8: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 8, target: TargetKind::RandomAlly }];
+static SHG_PROTECT_11: [EffectTemplate; 1] = [EffectTemplate::BlockGain { amount: 11, target: TargetKind::RandomAlly }];
+static SHG_SHIELD_BASH_6: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character }];
+static SHG_SHIELD_BASH_8: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 8, target: TargetKind::Character }];
+static GW_ULTIMATE_BLAST_25: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 25, target: TargetKind::Character }];
+static GW_ULTIMATE_BLAST_30: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 30, target: TargetKind::Character }];
+
+// Gremlin Leader (summons weighted 2:2:2:1:1 like the gremlin gang)
+pub static GREMLIN_POOL: [MonsterName; 8] = [
+    MonsterName::MadGremlin,
+    MonsterName::MadGremlin,
+    MonsterName::SneakyGremlin,
+    MonsterName::SneakyGremlin,
+    MonsterName::FatGremlin,
+    MonsterName::FatGremlin,
+    MonsterName::ShieldGremlin,
+    MonsterName::GremlinWizard,
+];
+static GL_RALLY: [EffectTemplate; 1] = [EffectTemplate::MonsterSummon { pool: &GREMLIN_POOL, count: 2 }];
+static GL_ENCOURAGE_3_6: [EffectTemplate; 2] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 3, target: TargetKind::AllMonsters },
+    EffectTemplate::BlockGain { amount: 6, target: TargetKind::AllAllies },
+];
+static GL_ENCOURAGE_4_6: [EffectTemplate; 2] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 4, target: TargetKind::AllMonsters },
+    EffectTemplate::BlockGain { amount: 6, target: TargetKind::AllAllies },
+];
+static GL_ENCOURAGE_5_10: [EffectTemplate; 2] = [
+    EffectTemplate::ModifierGain { kind: ModifierKind::Strength, stacks: 5, target: TargetKind::AllMonsters },
+    EffectTemplate::BlockGain { amount: 10, target: TargetKind::AllAllies },
+];
+static GL_STAB: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 6, target: TargetKind::Character },
+];
+
+// Reptomancer
+static RM_SUMMON_1: [EffectTemplate; 1] = [EffectTemplate::MonsterSummon { pool: &[MonsterName::Dagger], count: 1 }];
+static RM_SUMMON_2: [EffectTemplate; 1] = [EffectTemplate::MonsterSummon { pool: &[MonsterName::Dagger], count: 2 }];
+static RM_SNAKE_STRIKE_13: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 13, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static RM_SNAKE_STRIKE_16: [EffectTemplate; 3] = [
+    EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character },
+    EffectTemplate::DamagePhysical { base: 16, target: TargetKind::Character },
+    EffectTemplate::ModifierGain { kind: ModifierKind::Weak, stacks: 1, target: TargetKind::Character },
+];
+static RM_BIG_BITE_30: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 30, target: TargetKind::Character }];
+static RM_BIG_BITE_34: [EffectTemplate; 1] = [EffectTemplate::DamagePhysical { base: 34, target: TargetKind::Character }];
+
+// Dagger
+static DG_STAB: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 9, target: TargetKind::Character },
+    EffectTemplate::CardAdd { name: CardName::Wound, count: 1, pile: PileKind::Discard },
+];
+static DG_EXPLODE: [EffectTemplate; 2] = [
+    EffectTemplate::DamagePhysical { base: 25, target: TargetKind::Character },
+    EffectTemplate::Suicide,
+];
+
 // ---------------------------------------------------------------------------
 // Move indices (constants for readability)
 // ---------------------------------------------------------------------------
@@ -89,9 +757,16 @@ const CULT_DARK: usize = 1;
 const FB_BITE_IDX: usize = 0;
 const FB_GROW_IDX: usize = 1;
 
-// Louse Green: 0 = Bite, 1 = Grow
-const LG_BITE_IDX: usize = 0;
-const LG_GROW_IDX: usize = 1;
+// Louse: 0 = Bite, 1 = Grow (red) or Spit Web (green)
+const LOUSE_BITE_IDX: usize = 0;
+const LOUSE_SKILL_IDX: usize = 1;
+
+// Looter: 0 = Mug, 1 = Lunge, 2 = Smoke Bomb, 3 = Escape
+const LT_MUG_IDX: usize = 0;
+const LT_LUNGE_IDX: usize = 1;
+const LT_SMOKE_BOMB_IDX: usize = 2;
+const LT_ESCAPE_IDX: usize = 3;
+const LT_MUG_TURNS: usize = 2;
 
 // The Guardian: 0=ChargingUp, 1=FierceBash, 2=VentSteam, 3=Whirlwind, 4=DefensiveMode, 5=RollAttack, 6=TwinSlam
 const TG_CHARGING: usize = 0;
@@ -102,330 +777,2070 @@ const TG_DEFENSIVE: usize = 4;
 const TG_ROLL: usize = 5;
 const TG_TWIN: usize = 6;
 
+// Hexaghost: 0 = Activate, 1 = Divider, 2 = Sear, 3 = Tackle, 4 = Inflame, 5 = Inferno, 6 = Sear (Burn+)
+const HEX_ACTIVATE_IDX: usize = 0;
+const HEX_DIVIDER_IDX: usize = 1;
+const HEX_SEAR_IDX: usize = 2;
+const HEX_TACKLE_IDX: usize = 3;
+const HEX_INFLAME_IDX: usize = 4;
+const HEX_INFERNO_IDX: usize = 5;
+const HEX_SEAR_PLUS_IDX: usize = 6;
+const HEX_CYCLE: [usize; 7] = [
+    HEX_SEAR_IDX,
+    HEX_TACKLE_IDX,
+    HEX_SEAR_IDX,
+    HEX_INFLAME_IDX,
+    HEX_TACKLE_IDX,
+    HEX_SEAR_IDX,
+    HEX_INFERNO_IDX,
+];
+// Sear adds upgraded Burns once Inferno has been used
+const HEX_CYCLE_PLUS: [usize; 7] = [
+    HEX_SEAR_PLUS_IDX,
+    HEX_TACKLE_IDX,
+    HEX_SEAR_PLUS_IDX,
+    HEX_INFLAME_IDX,
+    HEX_TACKLE_IDX,
+    HEX_SEAR_PLUS_IDX,
+    HEX_INFERNO_IDX,
+];
+
+// Byrd: 0 = Peck, 1 = Caw, 2 = Swoop
+const BYRD_PECK_IDX: usize = 0;
+const BYRD_CAW_IDX: usize = 1;
+const BYRD_SWOOP_IDX: usize = 2;
+
+// Chosen: 0 = Poke, 1 = Zap, 2 = Debilitate, 3 = Drain
+const CH_POKE_IDX: usize = 0;
+const CH_ZAP_IDX: usize = 1;
+const CH_DEBILITATE_IDX: usize = 2;
+const CH_DRAIN_IDX: usize = 3;
+
+// The Champ: 0=DefensiveStance, 1=FaceSlap, 2=HeavySlash, 3=Gloat, 4=Taunt, 5=Execute, 6=Anger
+const TC_DEFENSIVE: usize = 0;
+const TC_FACE_SLAP: usize = 1;
+const TC_HEAVY_SLASH: usize = 2;
+const TC_GLOAT: usize = 3;
+const TC_TAUNT_IDX: usize = 4;
+const TC_EXECUTE_IDX: usize = 5;
+const TC_ANGER: usize = 6;
+const TC_TAUNT_EVERY_N_TURNS: usize = 4;
+const TC_DEFENSIVE_STANCE_MAX: usize = 2;
+
+// Awakened One: 0 = Slash, 1 = Soul Strike, then awakened 2 = Dark Echo, 3 = Sludge, 4 = Tackle
+const AO_SLASH_IDX: usize = 0;
+const AO_SOUL_STRIKE_IDX: usize = 1;
+const AO_DARK_ECHO_IDX: usize = 2;
+const AO_SLUDGE_IDX: usize = 3;
+const AO_TACKLE_IDX: usize = 4;
+
+// Time Eater: 0 = Reverberate, 1 = Ripple, 2 = Head Slam, 3 = Haste
+const TE_REVERBERATE_IDX: usize = 0;
+const TE_RIPPLE_IDX: usize = 1;
+const TE_HEAD_SLAM_IDX: usize = 2;
+const TE_HASTE_IDX: usize = 3;
+
+// Orb Walker: 0 = Laser, 1 = Claw
+const OW_LASER_IDX: usize = 0;
+const OW_CLAW_IDX: usize = 1;
+
+// Writhing Mass: 0 = Strong Hit, 1 = Multi-Strike, 2 = Flail, 3 = Wither
+const WM_STRONG_HIT_IDX: usize = 0;
+const WM_MULTI_STRIKE_IDX: usize = 1;
+const WM_FLAIL_IDX: usize = 2;
+const WM_WITHER_IDX: usize = 3;
+
+// Donu: 0 = Circle of Power, 1 = Beam. Deca: 0 = Beam, 1 = Square of Protection
+const DONU_CIRCLE_IDX: usize = 0;
+const DONU_BEAM_IDX: usize = 1;
+const DECA_BEAM_IDX: usize = 0;
+const DECA_SQUARE_IDX: usize = 1;
+
+// Repulsor: 0 = Bash, 1 = Repulse. Spiker: 0 = Cut, 1 = Spike. Exploder: 0 = Slam, 1 = Explode
+const RP_BASH_IDX: usize = 0;
+const RP_REPULSE_IDX: usize = 1;
+const SP_CUT_IDX: usize = 0;
+const SP_SPIKE_IDX: usize = 1;
+const EX_SLAM_IDX: usize = 0;
+const EX_EXPLODE_IDX: usize = 1;
+
+// Gremlin Nob: 0 = Bellow, 1 = Rush, 2 = Skull Bash
+const GN_BELLOW_IDX: usize = 0;
+const GN_RUSH_IDX: usize = 1;
+const GN_SKULL_BASH_IDX: usize = 2;
+
+// Blue Slaver: 0 = Stab, 1 = Rake
+const BS_STAB_IDX: usize = 0;
+const BS_RAKE_IDX: usize = 1;
+
+// Red Slaver: 0 = Stab, 1 = Scrape
+const RS_STAB_IDX: usize = 0;
+const RS_SCRAPE_IDX: usize = 1;
+
+// Giant Head: 0 = Glare, 1 = Count, 2.. = It Is Time (escalating)
+const GH_GLARE_IDX: usize = 0;
+const GH_COUNT_IDX: usize = 1;
+const GH_IT_IS_TIME_IDX: usize = 2;
+
+// Spire Shield: 0 = Bash, 1 = Fortify, 2 = Smash
+const SSH_BASH_IDX: usize = 0;
+const SSH_FORTIFY_IDX: usize = 1;
+const SSH_SMASH_IDX: usize = 2;
+
+// Spire Spear: 0 = Burn Strike, 1 = Piercer, 2 = Skewer
+const SSP_BURN_STRIKE_IDX: usize = 0;
+const SSP_PIERCER_IDX: usize = 1;
+const SSP_SKEWER_IDX: usize = 2;
+
+// Corrupt Heart: 0 = Debilitate, 1 = Blood Shots, 2 = Echo, 3 = Buff
+const CH_HEART_DEBILITATE_IDX: usize = 0;
+const CH_BLOOD_SHOTS_IDX: usize = 1;
+const CH_ECHO_IDX: usize = 2;
+const CH_HEART_BUFF_IDX: usize = 3;
+
+// Acid Slime (M, L): 0 = Corrosive Spit, 1 = Tackle, 2 = Lick, 3 = Split (L only). (S): 0 = Tackle, 1 = Lick
+const AS_SPIT_IDX: usize = 0;
+const AS_TACKLE_IDX: usize = 1;
+const AS_LICK_IDX: usize = 2;
+const AS_SPLIT_IDX: usize = 3;
+const AS_S_TACKLE_IDX: usize = 0;
+const AS_S_LICK_IDX: usize = 1;
+
+// Spike Slime (M, L): 0 = Flame Tackle, 1 = Lick, 2 = Split (L only). (S): 0 = Tackle
+const SS_FLAME_TACKLE_IDX: usize = 0;
+const SS_LICK_IDX: usize = 1;
+const SS_SPLIT_IDX: usize = 2;
+
+// Slime Boss: 0 = Goop Spray, 1 = Preparing, 2 = Slam, 3 = Split
+const SB_GOOP_SPRAY_IDX: usize = 0;
+const SB_PREPARING_IDX: usize = 1;
+const SB_SLAM_IDX: usize = 2;
+const SB_SPLIT_IDX: usize = 3;
+
+// Shield Gremlin: 0 = Protect, 1 = Shield Bash
+const SHG_PROTECT_IDX: usize = 0;
+const SHG_SHIELD_BASH_IDX: usize = 1;
+
+// Gremlin Wizard: 0 = Charging, 1 = Ultimate Blast
+const GW_CHARGING_IDX: usize = 0;
+const GW_ULTIMATE_BLAST_IDX: usize = 1;
+
+// Gremlin Leader: 0 = Rally, 1 = Encourage, 2 = Stab
+const GL_RALLY_IDX: usize = 0;
+const GL_ENCOURAGE_IDX: usize = 1;
+const GL_STAB_IDX: usize = 2;
+
+// Reptomancer: 0 = Summon, 1 = Snake Strike, 2 = Big Bite
+const RM_SUMMON_IDX: usize = 0;
+const RM_SNAKE_STRIKE_IDX: usize = 1;
+const RM_BIG_BITE_IDX: usize = 2;
+const RM_DAGGERS_MAX: usize = 4;
+
+// Dagger: 0 = Stab, 1 = Explode
+const DG_STAB_IDX: usize = 0;
+const DG_EXPLODE_IDX: usize = 1;
+
+
 // ---------------------------------------------------------------------------
-// Spawning
+// Monster definitions: data interpreted by `spawn_from_def` and
+// `next_move_from_rule`
 // ---------------------------------------------------------------------------
 
-pub fn spawn_monster(name: MonsterName, ascension: u8, rng: &mut impl Rng) -> Monster {
-    match name {
-        MonsterName::JawWorm => spawn_jaw_worm(ascension, rng),
-        MonsterName::Cultist => spawn_cultist(ascension, rng),
-        MonsterName::FungiBeast => spawn_fungi_beast(ascension, rng),
-        MonsterName::LouseGreen => spawn_louse_green(ascension, rng),
-        MonsterName::TheGuardian => spawn_the_guardian(ascension),
-        MonsterName::Dummy => spawn_dummy(),
-    }
-}
+/// Values per ascension: the last entry whose minimum ascension is reached applies.
+pub type Tiers<T> = &'static [(u8, T)];
 
-fn spawn_jaw_worm(asc: u8, rng: &mut impl Rng) -> Monster {
-    let (hmin, hmax) = if asc < 7 { (40, 44) } else { (42, 46) };
-    let hp = rng.random_range(hmin..=hmax);
+/// A move's effects and intent, one entry per variant.
+pub type MoveVariants = &'static [(&'static [EffectTemplate], Intent)];
 
-    let chomp_effects: &'static [EffectTemplate] = if asc < 2 { &JW_CHOMP_11 } else { &JW_CHOMP_12 };
-    let chomp_damage = if asc < 2 { 11 } else { 12 };
+/// Modifiers with the (min, max) of their stacks.
+pub type ModifierRanges = &'static [(ModifierKind, (i16, i16))];
 
-    let bellow_effects: &'static [EffectTemplate] = if asc < 2 {
-        &JW_BELLOW_3_6
-    } else if asc < 17 {
-        &JW_BELLOW_4_6
-    } else {
-        &JW_BELLOW_5_9
-    };
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveDef {
+    pub name: &'static str,
+    /// One variant per tier is rolled at spawn; most moves have only one.
+    pub tiers: Tiers<MoveVariants>,
+}
 
-    Monster {
-        name: MonsterName::JawWorm,
-        kind: MonsterKind::Normal,
-        vitals: vitals_new(hp, hp),
-        moves: vec![
-            Move { name: "Chomp", effects: chomp_effects, intent: Intent::attack(chomp_damage, 1) },
-            Move { name: "Bellow", effects: bellow_effects, intent: Intent::buff_block() },
-            Move { name: "Thrash", effects: &JW_THRASH, intent: Intent::attack_block(7, 1) },
-        ],
-        move_current: None,
-        move_history: Vec::new(),
-    }
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveRoll {
+    pub move_idx: usize,
+    pub weight: u32,
+    pub max_in_a_row: usize,
 }
 
-fn spawn_cultist(asc: u8, rng: &mut impl Rng) -> Monster {
-    let (hmin, hmax) = if asc < 7 { (48, 54) } else { (50, 56) };
-    let hp = rng.random_range(hmin..=hmax);
+/// What a `MoveRule::Branch` checks. Turns count the moves picked so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveCondition {
+    Always,
+    TurnBelow { turns: usize },
+    /// Turns `slot`, `slot + every`, `slot + 2 * every`, ... counting from 0.
+    TurnSlot { every: usize, slot: usize },
+    /// The move is among the last `turns` picked.
+    UsedWithin { move_idx: usize, turns: usize },
+    UsedTimes { move_idx: usize, times: usize },
+    HasModifier { kind: ModifierKind },
+    LacksModifier { kind: ModifierKind },
+    /// Fewer than `count` other monsters alive.
+    AlliesBelow { count: usize },
+    HealthBelowHalf,
+}
 
-    let incant_effects: &'static [EffectTemplate] = if asc < 2 {
-        &CULT_INCANTATION_3
-    } else if asc < 17 {
-        &CULT_INCANTATION_4
-    } else {
-        &CULT_INCANTATION_5
-    };
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveRule {
+    Fixed(usize),
+    /// The opening moves in order, then the cycle forever.
+    Cycle { opening: &'static [usize], cycle: &'static [usize] },
+    /// Like `Cycle`, but follows on from the last move instead of counting
+    /// turns. After a move it doesn't list it starts over, so another branch
+    /// can interrupt it (the Guardian's mode shift).
+    Sequence { opening: &'static [usize], cycle: &'static [usize] },
+    /// One weighted roll per turn after the opening. A move already used
+    /// `max_in_a_row` times in a row is rerolled among the allowed ones.
+    Weighted { opening: &'static [usize], rolls: &'static [MoveRoll] },
+    /// The rule of the first branch whose condition holds; the last branch is `Always`.
+    Branch { branches: &'static [(MoveCondition, MoveRule)] },
+}
 
-    Monster {
-        name: MonsterName::Cultist,
-        kind: MonsterKind::Normal,
-        vitals: vitals_new(hp, hp),
-        moves: vec![
-            Move { name: "Incantation", effects: incant_effects, intent: Intent::buff() },
-            Move { name: "Dark Strike", effects: &CULT_DARK_STRIKE, intent: Intent::attack(6, 1) },
-        ],
-        move_current: None,
-        move_history: Vec::new(),
-    }
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonsterDef {
+    pub name: MonsterName,
+    pub kind: MonsterKind,
+    pub health: Tiers<(u16, u16)>,
+    /// Stacks are rolled in range at spawn.
+    pub modifiers: Tiers<ModifierRanges>,
+    pub moves: &'static [MoveDef],
+    pub rule: Tiers<MoveRule>,
 }
 
-fn spawn_fungi_beast(asc: u8, rng: &mut impl Rng) -> Monster {
-    let (hmin, hmax) = if asc < 7 { (22, 28) } else { (24, 28) };
-    let hp = rng.random_range(hmin..=hmax);
+// Rules shared between monsters or ascension tiers
 
-    let grow_effects: &'static [EffectTemplate] = if asc < 2 {
-        &FB_GROW_3
-    } else if asc < 17 {
-        &FB_GROW_4
-    } else {
-        &FB_GROW_5
-    };
+const LOOTER_AI: MoveRule = MoveRule::Branch {
+    branches: &[
+        (MoveCondition::UsedWithin { move_idx: LT_SMOKE_BOMB_IDX, turns: 1 }, MoveRule::Fixed(LT_ESCAPE_IDX)),
+        (MoveCondition::UsedWithin { move_idx: LT_ESCAPE_IDX, turns: 1 }, MoveRule::Fixed(LT_ESCAPE_IDX)),
+        (MoveCondition::UsedWithin { move_idx: LT_LUNGE_IDX, turns: 1 }, MoveRule::Fixed(LT_SMOKE_BOMB_IDX)),
+        (MoveCondition::TurnBelow { turns: LT_MUG_TURNS }, MoveRule::Fixed(LT_MUG_IDX)),
+        (
+            MoveCondition::Always,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: LT_LUNGE_IDX, weight: 50, max_in_a_row: usize::MAX },
+                    MoveRoll { move_idx: LT_SMOKE_BOMB_IDX, weight: 50, max_in_a_row: usize::MAX },
+                ],
+            },
+        ),
+    ],
+};
 
-    let mut vitals = vitals_new(hp, hp);
-    modifier_apply(&mut vitals.modifiers, ModifierKind::SporeCloud, 2);
-    // Spore cloud starts as not new
-    vitals.modifiers.is_new[ModifierKind::SporeCloud as usize] = false;
+const CH_AFTER_DEBUFF: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: CH_ZAP_IDX, weight: 40, max_in_a_row: usize::MAX },
+        MoveRoll { move_idx: CH_POKE_IDX, weight: 59, max_in_a_row: usize::MAX },
+    ],
+};
 
-    Monster {
-        name: MonsterName::FungiBeast,
-        kind: MonsterKind::Normal,
-        vitals,
-        moves: vec![
-            Move { name: "Bite", effects: &FB_BITE, intent: Intent::attack(6, 1) },
-            Move { name: "Grow", effects: grow_effects, intent: Intent::buff() },
-        ],
-        move_current: None,
-        move_history: Vec::new(),
-    }
-}
+// Defensive Stance is limited per fight; nothing else repeats
+const TC_ROLLS_NO_STANCE: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: TC_GLOAT, weight: 31, max_in_a_row: 1 },
+        MoveRoll { move_idx: TC_FACE_SLAP, weight: 25, max_in_a_row: 1 },
+        MoveRoll { move_idx: TC_HEAVY_SLASH, weight: 43, max_in_a_row: 1 },
+    ],
+};
+const TC_ROLLS: MoveRule = MoveRule::Branch {
+    branches: &[
+        (MoveCondition::UsedTimes { move_idx: TC_DEFENSIVE, times: TC_DEFENSIVE_STANCE_MAX }, TC_ROLLS_NO_STANCE),
+        (
+            MoveCondition::Always,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: TC_DEFENSIVE, weight: 16, max_in_a_row: 1 },
+                    MoveRoll { move_idx: TC_GLOAT, weight: 15, max_in_a_row: 1 },
+                    MoveRoll { move_idx: TC_FACE_SLAP, weight: 25, max_in_a_row: 1 },
+                    MoveRoll { move_idx: TC_HEAVY_SLASH, weight: 43, max_in_a_row: 1 },
+                ],
+            },
+        ),
+    ],
+};
+const TC_ROLLS_A19: MoveRule = MoveRule::Branch {
+    branches: &[
+        (MoveCondition::UsedTimes { move_idx: TC_DEFENSIVE, times: TC_DEFENSIVE_STANCE_MAX }, TC_ROLLS_NO_STANCE),
+        (
+            MoveCondition::Always,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: TC_DEFENSIVE, weight: 31, max_in_a_row: 1 },
+                    MoveRoll { move_idx: TC_FACE_SLAP, weight: 25, max_in_a_row: 1 },
+                    MoveRoll { move_idx: TC_HEAVY_SLASH, weight: 43, max_in_a_row: 1 },
+                ],
+            },
+        ),
+    ],
+};
+// Anger below half health; from then on Execute every third turn and no more Taunts
+const TC_ANGER_USED: MoveCondition = MoveCondition::UsedTimes { move_idx: TC_ANGER, times: 1 };
+const TC_EXECUTED: MoveCondition = MoveCondition::UsedWithin { move_idx: TC_EXECUTE_IDX, turns: 2 };
+const TC_TAUNT_TURN: MoveCondition =
+    MoveCondition::TurnSlot { every: TC_TAUNT_EVERY_N_TURNS, slot: TC_TAUNT_EVERY_N_TURNS - 1 };
+const TC_AI: MoveRule = MoveRule::Branch {
+    branches: &[
+        (
+            TC_ANGER_USED,
+            MoveRule::Branch {
+                branches: &[(TC_EXECUTED, TC_ROLLS), (MoveCondition::Always, MoveRule::Fixed(TC_EXECUTE_IDX))],
+            },
+        ),
+        (MoveCondition::HealthBelowHalf, MoveRule::Fixed(TC_ANGER)),
+        (TC_TAUNT_TURN, MoveRule::Fixed(TC_TAUNT_IDX)),
+        (MoveCondition::Always, TC_ROLLS),
+    ],
+};
+const TC_AI_A19: MoveRule = MoveRule::Branch {
+    branches: &[
+        (
+            TC_ANGER_USED,
+            MoveRule::Branch {
+                branches: &[(TC_EXECUTED, TC_ROLLS_A19), (MoveCondition::Always, MoveRule::Fixed(TC_EXECUTE_IDX))],
+            },
+        ),
+        (MoveCondition::HealthBelowHalf, MoveRule::Fixed(TC_ANGER)),
+        (TC_TAUNT_TURN, MoveRule::Fixed(TC_TAUNT_IDX)),
+        (MoveCondition::Always, TC_ROLLS_A19),
+    ],
+};
 
-fn spawn_louse_green(asc: u8, rng: &mut impl Rng) -> Monster {
-    let (hmin, hmax) = if asc < 7 { (10, 15) } else { (11, 16) };
-    let hp = rng.random_range(hmin..=hmax);
+const TE_ROLLS: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: TE_REVERBERATE_IDX, weight: 45, max_in_a_row: 2 },
+        MoveRoll { move_idx: TE_HEAD_SLAM_IDX, weight: 35, max_in_a_row: 1 },
+        MoveRoll { move_idx: TE_RIPPLE_IDX, weight: 20, max_in_a_row: 1 },
+    ],
+};
 
-    let bite_damage: u16 = {
-        let base = rng.random_range(5u16..=7u16);
-        if asc >= 2 { base + 1 } else { base }
-    };
+const GH_ROLLS: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: GH_GLARE_IDX, weight: 50, max_in_a_row: 2 },
+        MoveRoll { move_idx: GH_COUNT_IDX, weight: 49, max_in_a_row: 2 },
+    ],
+};
+// Once the countdown runs out, It Is Time hits harder with every use
+const GH_IT_IS_TIME_AI: MoveRule = MoveRule::Sequence {
+    opening: &[
+        GH_IT_IS_TIME_IDX,
+        GH_IT_IS_TIME_IDX + 1,
+        GH_IT_IS_TIME_IDX + 2,
+        GH_IT_IS_TIME_IDX + 3,
+        GH_IT_IS_TIME_IDX + 4,
+        GH_IT_IS_TIME_IDX + 5,
+    ],
+    cycle: &[GH_IT_IS_TIME_IDX + 6],
+};
 
-    let grow_effects: &'static [EffectTemplate] = if asc < 17 { &LG_GROW_3 } else { &LG_GROW_4 };
+const AS_ROLLS: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: AS_SPIT_IDX, weight: 30, max_in_a_row: 2 },
+        MoveRoll { move_idx: AS_TACKLE_IDX, weight: 40, max_in_a_row: 1 },
+        MoveRoll { move_idx: AS_LICK_IDX, weight: 29, max_in_a_row: 2 },
+    ],
+};
+const AS_ROLLS_A17: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: AS_SPIT_IDX, weight: 40, max_in_a_row: 2 },
+        MoveRoll { move_idx: AS_TACKLE_IDX, weight: 40, max_in_a_row: 1 },
+        MoveRoll { move_idx: AS_LICK_IDX, weight: 19, max_in_a_row: 1 },
+    ],
+};
+const SS_ROLLS: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: SS_FLAME_TACKLE_IDX, weight: 30, max_in_a_row: 2 },
+        MoveRoll { move_idx: SS_LICK_IDX, weight: 69, max_in_a_row: 2 },
+    ],
+};
+const SS_ROLLS_A17: MoveRule = MoveRule::Weighted {
+    opening: &[],
+    rolls: &[
+        MoveRoll { move_idx: SS_FLAME_TACKLE_IDX, weight: 30, max_in_a_row: 2 },
+        MoveRoll { move_idx: SS_LICK_IDX, weight: 69, max_in_a_row: 1 },
+    ],
+};
+// Slimes split once they drop to half health and lose the Split modifier
+const SLIME_SPLIT: MoveCondition = MoveCondition::LacksModifier { kind: ModifierKind::Split };
 
-    // Curl up modifier (stored as a special initial modifier)
-    let curl_stacks: i16 = if asc < 7 {
-        rng.random_range(3..=7)
-    } else if asc < 17 {
-        rng.random_range(4..=8)
-    } else {
-        rng.random_range(9..=12)
-    };
-
-    let vitals = vitals_new(hp, hp);
-    // Note: CurlUp is not in our ModifierKind enum. For now we skip it since
-    // it's commented out in the Python code too. The Python uses it directly
-    // on the modifier_map but we don't have a CurlUp kind yet.
-    // TODO: add CurlUp modifier kind if needed
-    let _ = curl_stacks;
-
-    // Louse bite damage is rolled at spawn, so we need a dynamic move
-    // We'll create a fresh Move with the rolled damage as a static leak
-    // This is the one case where we need a non-static effects slice.
-    // For now, store the bite damage on the Intent and use a single-element vec.
-    let bite_effects: Vec<EffectTemplate> = vec![
-        EffectTemplate::DamagePhysical { base: bite_damage, target: TargetKind::Character },
-    ];
-    // Leak to get 'static lifetime (these are created once per monster spawn)
-    let bite_effects_static: &'static [EffectTemplate] = Box::leak(bite_effects.into_boxed_slice());
+// Louse bite damage is rolled at spawn
+static LOUSE_BITE: [(u8, MoveVariants); 2] = [
+    (
+        0,
+        &[
+            (&LOUSE_BITE_5, Intent::attack(5, 1)),
+            (&LOUSE_BITE_6, Intent::attack(6, 1)),
+            (&LOUSE_BITE_7, Intent::attack(7, 1)),
+        ],
+    ),
+    (
+        2,
+        &[
+            (&LOUSE_BITE_6, Intent::attack(6, 1)),
+            (&LOUSE_BITE_7, Intent::attack(7, 1)),
+            (&LOUSE_BITE_8, Intent::attack(8, 1)),
+        ],
+    ),
+];
+// Blocks the first attack that hurts it
+static LOUSE_CURL_UP: [(u8, ModifierRanges); 3] = [
+    (0, &[(ModifierKind::CurlUp, (3, 7))]),
+    (7, &[(ModifierKind::CurlUp, (4, 8))]),
+    (17, &[(ModifierKind::CurlUp, (9, 12))]),
+];
+static LOUSE_AI: [(u8, MoveRule); 2] = [
+    (
+        0,
+        MoveRule::Weighted {
+            opening: &[],
+            rolls: &[
+                MoveRoll { move_idx: LOUSE_BITE_IDX, weight: 60, max_in_a_row: 2 },
+                MoveRoll { move_idx: LOUSE_SKILL_IDX, weight: 39, max_in_a_row: 2 },
+            ],
+        },
+    ),
+    (
+        17,
+        MoveRule::Weighted {
+            opening: &[],
+            rolls: &[
+                MoveRoll { move_idx: LOUSE_BITE_IDX, weight: 60, max_in_a_row: 2 },
+                MoveRoll { move_idx: LOUSE_SKILL_IDX, weight: 39, max_in_a_row: 1 },
+            ],
+        },
+    ),
+];
 
-    Monster {
+
+static MONSTER_DEFS: [MonsterDef; 45] = [
+    MonsterDef {
+        name: MonsterName::Cultist,
+        kind: MonsterKind::Normal,
+        health: &[(0, (48, 54)), (7, (50, 56))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Incantation",
+                tiers: &[
+                    (0, &[(&CULT_INCANTATION_3, Intent::buff())]),
+                    (2, &[(&CULT_INCANTATION_4, Intent::buff())]),
+                    (17, &[(&CULT_INCANTATION_5, Intent::buff())]),
+                ],
+            },
+            MoveDef { name: "Dark Strike", tiers: &[(0, &[(&CULT_DARK_STRIKE, Intent::attack(6, 1))])] },
+        ],
+        rule: &[(0, MoveRule::Cycle { opening: &[CULT_INCANT], cycle: &[CULT_DARK] })],
+    },
+    MonsterDef {
+        name: MonsterName::FungiBeast,
+        kind: MonsterKind::Normal,
+        health: &[(0, (22, 28)), (7, (24, 28))],
+        modifiers: &[(0, &[(ModifierKind::SporeCloud, (2, 2))])],
+        moves: &[
+            MoveDef { name: "Bite", tiers: &[(0, &[(&FB_BITE, Intent::attack(6, 1))])] },
+            MoveDef {
+                name: "Grow",
+                tiers: &[
+                    (0, &[(&FB_GROW_3, Intent::buff())]),
+                    (2, &[(&FB_GROW_4, Intent::buff())]),
+                    (17, &[(&FB_GROW_5, Intent::buff())]),
+                ],
+            },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: FB_BITE_IDX, weight: 60, max_in_a_row: 2 },
+                    MoveRoll { move_idx: FB_GROW_IDX, weight: 39, max_in_a_row: 1 },
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::OrbWalker,
+        kind: MonsterKind::Normal,
+        health: &[(0, (90, 96)), (7, (92, 102))],
+        modifiers: &[(0, &[(ModifierKind::StrengthUp, (3, 3))]), (17, &[(ModifierKind::StrengthUp, (5, 5))])],
+        moves: &[
+            MoveDef {
+                name: "Laser",
+                tiers: &[
+                    (0, &[(&OW_LASER_10, Intent::attack_debuff(10, 1))]),
+                    (2, &[(&OW_LASER_11, Intent::attack_debuff(11, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Claw",
+                tiers: &[(0, &[(&OW_CLAW_15, Intent::attack(15, 1))]), (2, &[(&OW_CLAW_16, Intent::attack(16, 1))])],
+            },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: OW_CLAW_IDX, weight: 40, max_in_a_row: 2 },
+                    MoveRoll { move_idx: OW_LASER_IDX, weight: 59, max_in_a_row: 2 },
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Donu,
+        kind: MonsterKind::Boss,
+        health: &[(0, (250, 250)), (9, (265, 265))],
+        modifiers: &[(0, &[(ModifierKind::Artifact, (2, 2))]), (19, &[(ModifierKind::Artifact, (3, 3))])],
+        moves: &[
+            MoveDef { name: "Circle of Power", tiers: &[(0, &[(&DONU_CIRCLE_OF_POWER, Intent::buff())])] },
+            MoveDef {
+                name: "Beam",
+                tiers: &[(0, &[(&DONU_BEAM_10, Intent::attack(10, 2))]), (4, &[(&DONU_BEAM_12, Intent::attack(12, 2))])],
+            },
+        ],
+        rule: &[(0, MoveRule::Cycle { opening: &[], cycle: &[DONU_CIRCLE_IDX, DONU_BEAM_IDX] })],
+    },
+    MonsterDef {
+        name: MonsterName::Deca,
+        kind: MonsterKind::Boss,
+        health: &[(0, (250, 250)), (9, (265, 265))],
+        modifiers: &[(0, &[(ModifierKind::Artifact, (2, 2))]), (19, &[(ModifierKind::Artifact, (3, 3))])],
+        moves: &[
+            MoveDef {
+                name: "Beam",
+                tiers: &[
+                    (0, &[(&DECA_BEAM_10, Intent::attack_debuff(10, 2))]),
+                    (4, &[(&DECA_BEAM_12, Intent::attack_debuff(12, 2))]),
+                ],
+            },
+            MoveDef { name: "Square of Protection", tiers: &[(0, &[(&DECA_SQUARE_OF_PROTECTION, Intent::defend())])] },
+        ],
+        rule: &[(0, MoveRule::Cycle { opening: &[], cycle: &[DECA_BEAM_IDX, DECA_SQUARE_IDX] })],
+    },
+    MonsterDef {
+        name: MonsterName::Dagger,
+        kind: MonsterKind::Normal,
+        health: &[(0, (20, 25))],
+        // Daggers only ever fight alongside the Reptomancer
+        modifiers: &[(0, &[(ModifierKind::Minion, (1, 1))])],
+        moves: &[
+            MoveDef { name: "Stab", tiers: &[(0, &[(&DG_STAB, Intent::attack_debuff(9, 1))])] },
+            MoveDef { name: "Explode", tiers: &[(0, &[(&DG_EXPLODE, Intent::attack(25, 1))])] },
+        ],
+        rule: &[(0, MoveRule::Cycle { opening: &[DG_STAB_IDX], cycle: &[DG_EXPLODE_IDX] })],
+    },
+    MonsterDef {
+        name: MonsterName::Repulsor,
+        kind: MonsterKind::Normal,
+        health: &[(0, (29, 35)), (7, (31, 38))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Bash",
+                tiers: &[(0, &[(&RP_BASH_11, Intent::attack(11, 1))]), (2, &[(&RP_BASH_13, Intent::attack(13, 1))])],
+            },
+            MoveDef { name: "Repulse", tiers: &[(0, &[(&RP_REPULSE, Intent::debuff())])] },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: RP_BASH_IDX, weight: 20, max_in_a_row: 1 },
+                    MoveRoll { move_idx: RP_REPULSE_IDX, weight: 80, max_in_a_row: usize::MAX },
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Spiker,
+        kind: MonsterKind::Normal,
+        health: &[(0, (42, 56)), (7, (44, 60))],
+        modifiers: &[
+            (0, &[(ModifierKind::Thorns, (3, 3))]),
+            (2, &[(ModifierKind::Thorns, (4, 4))]),
+            (17, &[(ModifierKind::Thorns, (7, 7))]),
+        ],
+        moves: &[
+            MoveDef {
+                name: "Cut",
+                tiers: &[(0, &[(&SP_CUT_7, Intent::attack(7, 1))]), (2, &[(&SP_CUT_9, Intent::attack(9, 1))])],
+            },
+            MoveDef { name: "Spike", tiers: &[(0, &[(&SP_SPIKE, Intent::buff())])] },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Weighted {
+                opening: &[],
+                rolls: &[
+                    MoveRoll { move_idx: SP_CUT_IDX, weight: 50, max_in_a_row: 1 },
+                    MoveRoll { move_idx: SP_SPIKE_IDX, weight: 50, max_in_a_row: usize::MAX },
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Exploder,
+        kind: MonsterKind::Normal,
+        health: &[(0, (30, 30)), (7, (30, 35))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Slam",
+                tiers: &[(0, &[(&EX_SLAM_9, Intent::attack(9, 1))]), (2, &[(&EX_SLAM_11, Intent::attack(11, 1))])],
+            },
+            MoveDef { name: "Explode", tiers: &[(0, &[(&EX_EXPLODE, Intent::attack(30, 1))])] },
+        ],
+        // Explodes on its third turn
+        rule: &[(0, MoveRule::Cycle { opening: &[EX_SLAM_IDX, EX_SLAM_IDX], cycle: &[EX_EXPLODE_IDX] })],
+    },
+    MonsterDef {
+        name: MonsterName::JawWorm,
+        kind: MonsterKind::Normal,
+        health: &[(0, (40, 44)), (7, (42, 46))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Chomp",
+                tiers: &[(0, &[(&JW_CHOMP_11, Intent::attack(11, 1))]), (2, &[(&JW_CHOMP_12, Intent::attack(12, 1))])],
+            },
+            MoveDef {
+                name: "Bellow",
+                tiers: &[
+                    (0, &[(&JW_BELLOW_3_6, Intent::defend_buff())]),
+                    (2, &[(&JW_BELLOW_4_6, Intent::defend_buff())]),
+                    (17, &[(&JW_BELLOW_5_9, Intent::defend_buff())]),
+                ],
+            },
+            MoveDef { name: "Thrash", tiers: &[(0, &[(&JW_THRASH, Intent::attack_defend(7, 1))])] },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Weighted {
+                opening: &[JW_CHOMP_IDX],
+                rolls: &[
+                    MoveRoll { move_idx: JW_CHOMP_IDX, weight: 25, max_in_a_row: 1 },
+                    MoveRoll { move_idx: JW_THRASH_IDX, weight: 30, max_in_a_row: 2 },
+                    MoveRoll { move_idx: JW_BELLOW_IDX, weight: 44, max_in_a_row: 1 },
+                ],
+            },
+        )],
+    },
+    MonsterDef {
         name: MonsterName::LouseGreen,
         kind: MonsterKind::Normal,
-        vitals,
-        moves: vec![
-            Move { name: "Bite", effects: bite_effects_static, intent: Intent::attack(bite_damage, 1) },
-            Move { name: "Grow", effects: grow_effects, intent: Intent::buff() },
+        health: &[(0, (10, 15)), (7, (11, 16))],
+        modifiers: &LOUSE_CURL_UP,
+        moves: &[
+            MoveDef { name: "Bite", tiers: &LOUSE_BITE },
+            MoveDef { name: "Spit Web", tiers: &[(0, &[(&LOUSE_SPIT_WEB, Intent::debuff())])] },
         ],
-        move_current: None,
-        move_history: Vec::new(),
-    }
+        rule: &LOUSE_AI,
+    },
+    MonsterDef {
+        name: MonsterName::LouseRed,
+        kind: MonsterKind::Normal,
+        health: &[(0, (10, 15)), (7, (11, 16))],
+        modifiers: &LOUSE_CURL_UP,
+        moves: &[
+            MoveDef { name: "Bite", tiers: &LOUSE_BITE },
+            MoveDef {
+                name: "Grow",
+                tiers: &[(0, &[(&LOUSE_GROW_3, Intent::buff())]), (17, &[(&LOUSE_GROW_4, Intent::buff())])],
+            },
+        ],
+        rule: &LOUSE_AI,
+    },
+    MonsterDef {
+        name: MonsterName::Looter,
+        kind: MonsterKind::Normal,
+        health: &[(0, (44, 48)), (7, (46, 50))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Mug",
+                tiers: &[
+                    (0, &[(&LT_MUG_10_15, Intent::attack(10, 1))]),
+                    (2, &[(&LT_MUG_11_15, Intent::attack(11, 1))]),
+                    (17, &[(&LT_MUG_11_20, Intent::attack(11, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Lunge",
+                tiers: &[
+                    (0, &[(&LT_LUNGE_12_15, Intent::attack(12, 1))]),
+                    (2, &[(&LT_LUNGE_14_15, Intent::attack(14, 1))]),
+                    (17, &[(&LT_LUNGE_14_20, Intent::attack(14, 1))]),
+                ],
+            },
+            MoveDef { name: "Smoke Bomb", tiers: &[(0, &[(&LT_SMOKE_BOMB, Intent::defend())])] },
+            MoveDef { name: "Escape", tiers: &[(0, &[(&LT_ESCAPE, Intent::escape())])] },
+        ],
+        rule: &[(0, LOOTER_AI)],
+    },
+    // Same move order as the Looter, which shares its AI
+    MonsterDef {
+        name: MonsterName::Mugger,
+        kind: MonsterKind::Normal,
+        health: &[(0, (48, 52)), (7, (50, 54))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Mug",
+                tiers: &[
+                    (0, &[(&MG_MUG_10_15, Intent::attack(10, 1))]),
+                    (2, &[(&MG_MUG_11_15, Intent::attack(11, 1))]),
+                    (17, &[(&MG_MUG_11_20, Intent::attack(11, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Lunge",
+                tiers: &[
+                    (0, &[(&MG_LUNGE_16_15, Intent::attack(16, 1))]),
+                    (2, &[(&MG_LUNGE_18_15, Intent::attack(18, 1))]),
+                    (17, &[(&MG_LUNGE_18_20, Intent::attack(18, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Smoke Bomb",
+                tiers: &[(0, &[(&MG_SMOKE_BOMB_11, Intent::defend())]), (17, &[(&MG_SMOKE_BOMB_17, Intent::defend())])],
+            },
+            MoveDef { name: "Escape", tiers: &[(0, &[(&LT_ESCAPE, Intent::escape())])] },
+        ],
+        rule: &[(0, LOOTER_AI)],
+    },
+    MonsterDef {
+        name: MonsterName::TheGuardian,
+        kind: MonsterKind::Boss,
+        health: &[(0, (240, 240)), (9, (250, 250))],
+        modifiers: &[
+            (0, &[(ModifierKind::ModeShift, (30, 30))]),
+            (9, &[(ModifierKind::ModeShift, (35, 35))]),
+            (19, &[(ModifierKind::ModeShift, (40, 40))]),
+        ],
+        moves: &[
+            MoveDef { name: "Charging Up", tiers: &[(0, &[(&TG_CHARGING_UP, Intent::defend())])] },
+            MoveDef {
+                name: "Fierce Bash",
+                tiers: &[
+                    (0, &[(&TG_FIERCE_BASH_32, Intent::attack(32, 1))]),
+                    (4, &[(&TG_FIERCE_BASH_36, Intent::attack(36, 1))]),
+                ],
+            },
+            MoveDef { name: "Vent Steam", tiers: &[(0, &[(&TG_VENT_STEAM, Intent::strong_debuff())])] },
+            MoveDef { name: "Whirlwind", tiers: &[(0, &[(&TG_WHIRLWIND, Intent::attack(5, 4))])] },
+            MoveDef {
+                name: "Defensive Mode",
+                tiers: &[(0, &[(&TG_DEFENSIVE_MODE_3, Intent::buff())]), (19, &[(&TG_DEFENSIVE_MODE_4, Intent::buff())])],
+            },
+            MoveDef {
+                name: "Roll Attack",
+                tiers: &[(0, &[(&TG_ROLL_ATTACK_9, Intent::attack(9, 1))]), (4, &[(&TG_ROLL_ATTACK_10, Intent::attack(10, 1))])],
+            },
+            MoveDef { name: "Twin Slam", tiers: &[(0, &[(&TG_TWIN_SLAM, Intent::attack_buff(8, 2))])] },
+        ],
+        // Breaking Mode Shift starts the defensive moves; Twin Slam restores it
+        // and the offensive moves resume at Whirlwind
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::LacksModifier { kind: ModifierKind::ModeShift },
+                        MoveRule::Sequence { opening: &[], cycle: &[TG_DEFENSIVE, TG_ROLL, TG_TWIN] },
+                    ),
+                    (MoveCondition::TurnBelow { turns: 1 }, MoveRule::Fixed(TG_CHARGING)),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Sequence { opening: &[], cycle: &[TG_WHIRL, TG_CHARGING, TG_FIERCE, TG_VENT] },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Hexaghost,
+        kind: MonsterKind::Boss,
+        health: &[(0, (250, 250)), (9, (264, 264))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef { name: "Activate", tiers: &[(0, &[(&[], Intent::unknown())])] },
+            // Damage depends on the character's health when it lands
+            MoveDef {
+                name: "Divider",
+                tiers: &[(0, &[(&HEX_DIVIDER, Intent { damage: None, ..Intent::attack(0, 6) })])],
+            },
+            MoveDef {
+                name: "Sear",
+                tiers: &[(0, &[(&HEX_SEAR_1, Intent::attack_debuff(6, 1))]), (19, &[(&HEX_SEAR_2, Intent::attack_debuff(6, 1))])],
+            },
+            MoveDef {
+                name: "Tackle",
+                tiers: &[(0, &[(&HEX_TACKLE_5, Intent::attack(5, 2))]), (4, &[(&HEX_TACKLE_6, Intent::attack(6, 2))])],
+            },
+            MoveDef {
+                name: "Inflame",
+                tiers: &[(0, &[(&HEX_INFLAME_2, Intent::defend_buff())]), (19, &[(&HEX_INFLAME_3, Intent::defend_buff())])],
+            },
+            MoveDef {
+                name: "Inferno",
+                tiers: &[
+                    (0, &[(&HEX_INFERNO_2, Intent::attack_debuff(2, 6))]),
+                    (4, &[(&HEX_INFERNO_3, Intent::attack_debuff(3, 6))]),
+                ],
+            },
+            MoveDef {
+                name: "Sear",
+                tiers: &[
+                    (0, &[(&HEX_SEAR_PLUS_1, Intent::attack_debuff(6, 1))]),
+                    (19, &[(&HEX_SEAR_PLUS_2, Intent::attack_debuff(6, 1))]),
+                ],
+            },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::UsedTimes { move_idx: HEX_INFERNO_IDX, times: 1 },
+                        MoveRule::Cycle { opening: &[HEX_ACTIVATE_IDX, HEX_DIVIDER_IDX], cycle: &HEX_CYCLE_PLUS },
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Cycle { opening: &[HEX_ACTIVATE_IDX, HEX_DIVIDER_IDX], cycle: &HEX_CYCLE },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Byrd,
+        kind: MonsterKind::Normal,
+        health: &[(0, (25, 31)), (7, (26, 33))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Peck",
+                tiers: &[(0, &[(&BYRD_PECK_5, Intent::attack(1, 5))]), (2, &[(&BYRD_PECK_6, Intent::attack(1, 6))])],
+            },
+            MoveDef { name: "Caw", tiers: &[(0, &[(&BYRD_CAW, Intent::buff())])] },
+            MoveDef {
+                name: "Swoop",
+                tiers: &[(0, &[(&BYRD_SWOOP_12, Intent::attack(12, 1))]), (2, &[(&BYRD_SWOOP_14, Intent::attack(14, 1))])],
+            },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::TurnBelow { turns: 1 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: BYRD_CAW_IDX, weight: 3, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: BYRD_PECK_IDX, weight: 5, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: BYRD_PECK_IDX, weight: 50, max_in_a_row: 2 },
+                                MoveRoll { move_idx: BYRD_SWOOP_IDX, weight: 20, max_in_a_row: 1 },
+                                MoveRoll { move_idx: BYRD_CAW_IDX, weight: 29, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Chosen,
+        kind: MonsterKind::Normal,
+        health: &[(0, (95, 99)), (7, (98, 103))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Poke",
+                tiers: &[(0, &[(&CH_POKE_5, Intent::attack(5, 2))]), (2, &[(&CH_POKE_6, Intent::attack(6, 2))])],
+            },
+            MoveDef {
+                name: "Zap",
+                tiers: &[(0, &[(&CH_ZAP_18, Intent::attack(18, 1))]), (2, &[(&CH_ZAP_21, Intent::attack(21, 1))])],
+            },
+            MoveDef {
+                name: "Debilitate",
+                tiers: &[
+                    (0, &[(&CH_DEBILITATE_10, Intent::attack_debuff(10, 1))]),
+                    (2, &[(&CH_DEBILITATE_12, Intent::attack_debuff(12, 1))]),
+                ],
+            },
+            MoveDef { name: "Drain", tiers: &[(0, &[(&CH_DRAIN, Intent::debuff())])] },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::TurnBelow { turns: 1 }, MoveRule::Fixed(CH_POKE_IDX)),
+                    (MoveCondition::UsedWithin { move_idx: CH_DEBILITATE_IDX, turns: 1 }, CH_AFTER_DEBUFF),
+                    (MoveCondition::UsedWithin { move_idx: CH_DRAIN_IDX, turns: 1 }, CH_AFTER_DEBUFF),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: CH_DEBILITATE_IDX, weight: 50, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: CH_DRAIN_IDX, weight: 49, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::TheChamp,
+        kind: MonsterKind::Boss,
+        health: &[(0, (420, 420)), (9, (440, 440))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Defensive Stance",
+                tiers: &[
+                    (0, &[(&TC_DEFENSIVE_STANCE_15, Intent::defend_buff())]),
+                    (9, &[(&TC_DEFENSIVE_STANCE_18, Intent::defend_buff())]),
+                    (19, &[(&TC_DEFENSIVE_STANCE_20, Intent::defend_buff())]),
+                ],
+            },
+            MoveDef {
+                name: "Face Slap",
+                tiers: &[
+                    (0, &[(&TC_FACE_SLAP_12, Intent::attack_debuff(12, 1))]),
+                    (4, &[(&TC_FACE_SLAP_14, Intent::attack_debuff(14, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Heavy Slash",
+                tiers: &[
+                    (0, &[(&TC_HEAVY_SLASH_16, Intent::attack(16, 1))]),
+                    (4, &[(&TC_HEAVY_SLASH_18, Intent::attack(18, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Gloat",
+                tiers: &[
+                    (0, &[(&TC_GLOAT_2, Intent::buff())]),
+                    (4, &[(&TC_GLOAT_3, Intent::buff())]),
+                    (19, &[(&TC_GLOAT_4, Intent::buff())]),
+                ],
+            },
+            MoveDef { name: "Taunt", tiers: &[(0, &[(&TC_TAUNT, Intent::debuff())])] },
+            MoveDef { name: "Execute", tiers: &[(0, &[(&TC_EXECUTE, Intent::attack(10, 2))])] },
+            MoveDef {
+                name: "Anger",
+                tiers: &[
+                    (0, &[(&TC_ANGER_6, Intent::buff())]),
+                    (4, &[(&TC_ANGER_9, Intent::buff())]),
+                    (19, &[(&TC_ANGER_12, Intent::buff())]),
+                ],
+            },
+        ],
+        rule: &[(0, TC_AI), (19, TC_AI_A19)],
+    },
+    MonsterDef {
+        name: MonsterName::WrithingMass,
+        kind: MonsterKind::Normal,
+        health: &[(0, (160, 160)), (7, (175, 175))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Strong Hit",
+                tiers: &[(0, &[(&WM_STRONG_HIT_32, Intent::attack(32, 1))]), (2, &[(&WM_STRONG_HIT_38, Intent::attack(38, 1))])],
+            },
+            MoveDef {
+                name: "Multi-Strike",
+                tiers: &[(0, &[(&WM_MULTI_STRIKE_7, Intent::attack(7, 3))]), (2, &[(&WM_MULTI_STRIKE_9, Intent::attack(9, 3))])],
+            },
+            MoveDef {
+                name: "Flail",
+                tiers: &[
+                    (0, &[(&WM_FLAIL_15, Intent::attack_defend(15, 1))]),
+                    (2, &[(&WM_FLAIL_16, Intent::attack_defend(16, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Wither",
+                tiers: &[
+                    (0, &[(&WM_WITHER_10, Intent::attack_debuff(10, 1))]),
+                    (2, &[(&WM_WITHER_12, Intent::attack_debuff(12, 1))]),
+                ],
+            },
+        ],
+        // Never repeats its previous move
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::TurnBelow { turns: 1 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: WM_MULTI_STRIKE_IDX, weight: 33, max_in_a_row: 1 },
+                                MoveRoll { move_idx: WM_FLAIL_IDX, weight: 33, max_in_a_row: 1 },
+                                MoveRoll { move_idx: WM_WITHER_IDX, weight: 33, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: WM_STRONG_HIT_IDX, weight: 10, max_in_a_row: 1 },
+                                MoveRoll { move_idx: WM_WITHER_IDX, weight: 30, max_in_a_row: 1 },
+                                MoveRoll { move_idx: WM_MULTI_STRIKE_IDX, weight: 30, max_in_a_row: 1 },
+                                MoveRoll { move_idx: WM_FLAIL_IDX, weight: 29, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::AwakenedOne,
+        kind: MonsterKind::Boss,
+        health: &[(0, (300, 300)), (9, (320, 320))],
+        modifiers: &[
+            (
+                0,
+                &[
+                    (ModifierKind::Curiosity, (1, 1)),
+                    (ModifierKind::Regenerate, (10, 10)),
+                    (ModifierKind::Unawakened, (1, 1)),
+                ],
+            ),
+            (
+                4,
+                &[
+                    (ModifierKind::Curiosity, (1, 1)),
+                    (ModifierKind::Regenerate, (10, 10)),
+                    (ModifierKind::Unawakened, (1, 1)),
+                    (ModifierKind::Strength, (2, 2)),
+                ],
+            ),
+            (
+                19,
+                &[
+                    (ModifierKind::Curiosity, (2, 2)),
+                    (ModifierKind::Regenerate, (15, 15)),
+                    (ModifierKind::Unawakened, (1, 1)),
+                    (ModifierKind::Strength, (2, 2)),
+                ],
+            ),
+        ],
+        moves: &[
+            MoveDef { name: "Slash", tiers: &[(0, &[(&AO_SLASH, Intent::attack(20, 1))])] },
+            MoveDef { name: "Soul Strike", tiers: &[(0, &[(&AO_SOUL_STRIKE, Intent::attack(6, 4))])] },
+            MoveDef { name: "Dark Echo", tiers: &[(0, &[(&AO_DARK_ECHO, Intent::attack(40, 1))])] },
+            MoveDef { name: "Sludge", tiers: &[(0, &[(&AO_SLUDGE, Intent::attack_debuff(18, 1))])] },
+            MoveDef { name: "Tackle", tiers: &[(0, &[(&AO_TACKLE, Intent::attack(10, 3))])] },
+        ],
+        // Once awakened it opens with Dark Echo
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::HasModifier { kind: ModifierKind::Unawakened },
+                        MoveRule::Weighted {
+                            opening: &[AO_SLASH_IDX],
+                            rolls: &[
+                                MoveRoll { move_idx: AO_SOUL_STRIKE_IDX, weight: 25, max_in_a_row: 1 },
+                                MoveRoll { move_idx: AO_SLASH_IDX, weight: 75, max_in_a_row: 2 },
+                            ],
+                        },
+                    ),
+                    (MoveCondition::TurnBelow { turns: 1 }, MoveRule::Fixed(AO_DARK_ECHO_IDX)),
+                    (MoveCondition::UsedWithin { move_idx: AO_SLASH_IDX, turns: 1 }, MoveRule::Fixed(AO_DARK_ECHO_IDX)),
+                    (
+                        MoveCondition::UsedWithin { move_idx: AO_SOUL_STRIKE_IDX, turns: 1 },
+                        MoveRule::Fixed(AO_DARK_ECHO_IDX),
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: AO_SLUDGE_IDX, weight: 50, max_in_a_row: 2 },
+                                MoveRoll { move_idx: AO_TACKLE_IDX, weight: 50, max_in_a_row: 2 },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::TimeEater,
+        kind: MonsterKind::Boss,
+        health: &[(0, (456, 456)), (9, (480, 480))],
+        modifiers: &[(0, &[(ModifierKind::TimeWarp, (0, 0))])],
+        moves: &[
+            MoveDef {
+                name: "Reverberate",
+                tiers: &[
+                    (0, &[(&TE_REVERBERATE_7, Intent::attack(7, 3))]),
+                    (4, &[(&TE_REVERBERATE_8, Intent::attack(8, 3))]),
+                ],
+            },
+            MoveDef {
+                name: "Ripple",
+                tiers: &[(0, &[(&TE_RIPPLE, Intent::defend_debuff())]), (19, &[(&TE_RIPPLE_FRAIL, Intent::defend_debuff())])],
+            },
+            MoveDef {
+                name: "Head Slam",
+                tiers: &[
+                    (0, &[(&TE_HEAD_SLAM_26, Intent::attack_debuff(26, 1))]),
+                    (4, &[(&TE_HEAD_SLAM_32, Intent::attack_debuff(32, 1))]),
+                    (19, &[(&TE_HEAD_SLAM_32_SLIMED, Intent::attack_debuff(32, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Haste",
+                tiers: &[(0, &[(&TE_HASTE, Intent::buff())]), (19, &[(&TE_HASTE_32, Intent::buff())])],
+            },
+        ],
+        // Haste once below half health
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::UsedTimes { move_idx: TE_HASTE_IDX, times: 1 }, TE_ROLLS),
+                    (MoveCondition::HealthBelowHalf, MoveRule::Fixed(TE_HASTE_IDX)),
+                    (MoveCondition::Always, TE_ROLLS),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::GremlinNob,
+        kind: MonsterKind::Elite,
+        health: &[(0, (82, 86)), (8, (85, 90))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Bellow",
+                tiers: &[(0, &[(&GN_BELLOW_2, Intent::buff())]), (18, &[(&GN_BELLOW_3, Intent::buff())])],
+            },
+            MoveDef {
+                name: "Rush",
+                tiers: &[(0, &[(&GN_RUSH_14, Intent::attack(14, 1))]), (3, &[(&GN_RUSH_16, Intent::attack(16, 1))])],
+            },
+            MoveDef {
+                name: "Skull Bash",
+                tiers: &[
+                    (0, &[(&GN_SKULL_BASH_6, Intent::attack_debuff(6, 1))]),
+                    (3, &[(&GN_SKULL_BASH_8, Intent::attack_debuff(8, 1))]),
+                ],
+            },
+        ],
+        // From A18 it never goes two turns without a Skull Bash
+        rule: &[
+            (
+                0,
+                MoveRule::Weighted {
+                    opening: &[GN_BELLOW_IDX],
+                    rolls: &[
+                        MoveRoll { move_idx: GN_SKULL_BASH_IDX, weight: 33, max_in_a_row: usize::MAX },
+                        MoveRoll { move_idx: GN_RUSH_IDX, weight: 66, max_in_a_row: 2 },
+                    ],
+                },
+            ),
+            (
+                18,
+                MoveRule::Cycle { opening: &[GN_BELLOW_IDX], cycle: &[GN_SKULL_BASH_IDX, GN_RUSH_IDX, GN_RUSH_IDX] },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::BlueSlaver,
+        kind: MonsterKind::Normal,
+        health: &[(0, (46, 50)), (7, (48, 52))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Stab",
+                tiers: &[(0, &[(&BS_STAB_12, Intent::attack(12, 1))]), (2, &[(&BS_STAB_13, Intent::attack(13, 1))])],
+            },
+            MoveDef {
+                name: "Rake",
+                tiers: &[
+                    (0, &[(&BS_RAKE_7_1, Intent::attack_debuff(7, 1))]),
+                    (2, &[(&BS_RAKE_8_1, Intent::attack_debuff(8, 1))]),
+                    (17, &[(&BS_RAKE_8_2, Intent::attack_debuff(8, 1))]),
+                ],
+            },
+        ],
+        rule: &[
+            (
+                0,
+                MoveRule::Weighted {
+                    opening: &[],
+                    rolls: &[
+                        MoveRoll { move_idx: BS_STAB_IDX, weight: 59, max_in_a_row: 2 },
+                        MoveRoll { move_idx: BS_RAKE_IDX, weight: 40, max_in_a_row: 2 },
+                    ],
+                },
+            ),
+            (
+                17,
+                MoveRule::Weighted {
+                    opening: &[],
+                    rolls: &[
+                        MoveRoll { move_idx: BS_STAB_IDX, weight: 59, max_in_a_row: 2 },
+                        MoveRoll { move_idx: BS_RAKE_IDX, weight: 40, max_in_a_row: 1 },
+                    ],
+                },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::RedSlaver,
+        kind: MonsterKind::Normal,
+        health: &[(0, (46, 50)), (7, (48, 52))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Stab",
+                tiers: &[(0, &[(&RS_STAB_13, Intent::attack(13, 1))]), (2, &[(&RS_STAB_14, Intent::attack(14, 1))])],
+            },
+            MoveDef {
+                name: "Scrape",
+                tiers: &[
+                    (0, &[(&RS_SCRAPE_8_1, Intent::attack_debuff(8, 1))]),
+                    (2, &[(&RS_SCRAPE_9_1, Intent::attack_debuff(9, 1))]),
+                    (17, &[(&RS_SCRAPE_9_2, Intent::attack_debuff(9, 1))]),
+                ],
+            },
+        ],
+        rule: &[
+            (
+                0,
+                MoveRule::Weighted {
+                    opening: &[RS_STAB_IDX],
+                    rolls: &[
+                        MoveRoll { move_idx: RS_STAB_IDX, weight: 44, max_in_a_row: 2 },
+                        MoveRoll { move_idx: RS_SCRAPE_IDX, weight: 55, max_in_a_row: 2 },
+                    ],
+                },
+            ),
+            (
+                17,
+                MoveRule::Weighted {
+                    opening: &[RS_STAB_IDX],
+                    rolls: &[
+                        MoveRoll { move_idx: RS_STAB_IDX, weight: 44, max_in_a_row: 2 },
+                        MoveRoll { move_idx: RS_SCRAPE_IDX, weight: 55, max_in_a_row: 1 },
+                    ],
+                },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::Taskmaster,
+        kind: MonsterKind::Elite,
+        health: &[(0, (54, 60)), (8, (57, 64))],
+        modifiers: &[(0, &[])],
+        moves: &[MoveDef {
+            name: "Scouring Whip",
+            tiers: &[
+                (0, &[(&TM_SCOURING_WHIP_1, Intent::attack_debuff(7, 1))]),
+                (3, &[(&TM_SCOURING_WHIP_2, Intent::attack_debuff(7, 1))]),
+                (18, &[(&TM_SCOURING_WHIP_3, Intent::attack_debuff(7, 1))]),
+            ],
+        }],
+        rule: &[(0, MoveRule::Fixed(0))],
+    },
+    // It Is Time starts at 30 (40 from A3) and grows by 5 per use, up to +30
+    MonsterDef {
+        name: MonsterName::GiantHead,
+        kind: MonsterKind::Elite,
+        health: &[(0, (500, 500)), (8, (520, 520))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef { name: "Glare", tiers: &[(0, &[(&GH_GLARE, Intent::debuff())])] },
+            MoveDef { name: "Count", tiers: &[(0, &[(&GH_COUNT, Intent::attack(13, 1))])] },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_30, Intent::attack(30, 1))]), (3, &[(&GH_IT_IS_TIME_40, Intent::attack(40, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_35, Intent::attack(35, 1))]), (3, &[(&GH_IT_IS_TIME_45, Intent::attack(45, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_40, Intent::attack(40, 1))]), (3, &[(&GH_IT_IS_TIME_50, Intent::attack(50, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_45, Intent::attack(45, 1))]), (3, &[(&GH_IT_IS_TIME_55, Intent::attack(55, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_50, Intent::attack(50, 1))]), (3, &[(&GH_IT_IS_TIME_60, Intent::attack(60, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_55, Intent::attack(55, 1))]), (3, &[(&GH_IT_IS_TIME_65, Intent::attack(65, 1))])],
+            },
+            MoveDef {
+                name: "It Is Time",
+                tiers: &[(0, &[(&GH_IT_IS_TIME_60, Intent::attack(60, 1))]), (3, &[(&GH_IT_IS_TIME_70, Intent::attack(70, 1))])],
+            },
+        ],
+        // The countdown lasts five turns, four from A18
+        rule: &[
+            (
+                0,
+                MoveRule::Branch {
+                    branches: &[(MoveCondition::TurnBelow { turns: 5 }, GH_ROLLS), (MoveCondition::Always, GH_IT_IS_TIME_AI)],
+                },
+            ),
+            (
+                18,
+                MoveRule::Branch {
+                    branches: &[(MoveCondition::TurnBelow { turns: 4 }, GH_ROLLS), (MoveCondition::Always, GH_IT_IS_TIME_AI)],
+                },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::SpireShield,
+        kind: MonsterKind::Elite,
+        health: &[(0, (110, 110)), (8, (125, 125))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Bash",
+                tiers: &[(0, &[(&SSH_BASH_12, Intent::attack_debuff(12, 1))]), (3, &[(&SSH_BASH_14, Intent::attack_debuff(14, 1))])],
+            },
+            MoveDef { name: "Fortify", tiers: &[(0, &[(&SSH_FORTIFY, Intent::defend())])] },
+            MoveDef {
+                name: "Smash",
+                tiers: &[
+                    (0, &[(&SSH_SMASH_34_34, Intent::attack_defend(34, 1))]),
+                    (3, &[(&SSH_SMASH_38_38, Intent::attack_defend(38, 1))]),
+                    (18, &[(&SSH_SMASH_38_99, Intent::attack_defend(38, 1))]),
+                ],
+            },
+        ],
+        // Three-turn rounds ending in Smash
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::TurnSlot { every: 3, slot: 2 }, MoveRule::Fixed(SSH_SMASH_IDX)),
+                    (
+                        MoveCondition::TurnSlot { every: 3, slot: 0 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: SSH_FORTIFY_IDX, weight: 1, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: SSH_BASH_IDX, weight: 1, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                    (MoveCondition::UsedWithin { move_idx: SSH_BASH_IDX, turns: 1 }, MoveRule::Fixed(SSH_FORTIFY_IDX)),
+                    (MoveCondition::Always, MoveRule::Fixed(SSH_BASH_IDX)),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::SpireSpear,
+        kind: MonsterKind::Elite,
+        health: &[(0, (160, 160)), (8, (180, 180))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            // From A18 the Burns go into the draw pile
+            MoveDef {
+                name: "Burn Strike",
+                tiers: &[
+                    (0, &[(&SSP_BURN_STRIKE_5, Intent::attack_debuff(5, 2))]),
+                    (3, &[(&SSP_BURN_STRIKE_6, Intent::attack_debuff(6, 2))]),
+                    (18, &[(&SSP_BURN_STRIKE_6_DRAW, Intent::attack_debuff(6, 2))]),
+                ],
+            },
+            MoveDef { name: "Piercer", tiers: &[(0, &[(&SSP_PIERCER, Intent::buff())])] },
+            MoveDef {
+                name: "Skewer",
+                tiers: &[(0, &[(&SSP_SKEWER_3, Intent::attack(10, 3))]), (3, &[(&SSP_SKEWER_4, Intent::attack(10, 4))])],
+            },
+        ],
+        // Three-turn rounds with Skewer in the middle
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::TurnSlot { every: 3, slot: 1 }, MoveRule::Fixed(SSP_SKEWER_IDX)),
+                    (
+                        MoveCondition::TurnSlot { every: 3, slot: 2 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: SSP_PIERCER_IDX, weight: 1, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: SSP_BURN_STRIKE_IDX, weight: 1, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::UsedWithin { move_idx: SSP_BURN_STRIKE_IDX, turns: 1 },
+                        MoveRule::Fixed(SSP_PIERCER_IDX),
+                    ),
+                    (MoveCondition::Always, MoveRule::Fixed(SSP_BURN_STRIKE_IDX)),
+                ],
+            },
+        )],
+    },
+    // Invincible matches `heart_invincible_cap`
+    MonsterDef {
+        name: MonsterName::CorruptHeart,
+        kind: MonsterKind::Boss,
+        health: &[(0, (750, 750)), (9, (800, 800))],
+        modifiers: &[
+            (0, &[(ModifierKind::Invincible, (300, 300)), (ModifierKind::BeatOfDeath, (1, 1))]),
+            (19, &[(ModifierKind::Invincible, (200, 200)), (ModifierKind::BeatOfDeath, (2, 2))]),
+        ],
+        moves: &[
+            MoveDef { name: "Debilitate", tiers: &[(0, &[(&CH_HEART_DEBILITATE, Intent::strong_debuff())])] },
+            MoveDef {
+                name: "Blood Shots",
+                tiers: &[(0, &[(&CH_BLOOD_SHOTS_12, Intent::attack(2, 12))]), (4, &[(&CH_BLOOD_SHOTS_15, Intent::attack(2, 15))])],
+            },
+            MoveDef {
+                name: "Echo",
+                tiers: &[(0, &[(&CH_ECHO_40, Intent::attack(40, 1))]), (4, &[(&CH_ECHO_45, Intent::attack(45, 1))])],
+            },
+            MoveDef { name: "Buff", tiers: &[(0, &[(&CH_HEART_BUFF, Intent::buff())])] },
+        ],
+        // After Debilitate: two attacks in either order, then a buff
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::TurnBelow { turns: 1 }, MoveRule::Fixed(CH_HEART_DEBILITATE_IDX)),
+                    (MoveCondition::TurnSlot { every: 3, slot: 0 }, MoveRule::Fixed(CH_HEART_BUFF_IDX)),
+                    (
+                        MoveCondition::TurnSlot { every: 3, slot: 1 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: CH_BLOOD_SHOTS_IDX, weight: 1, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: CH_ECHO_IDX, weight: 1, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                    (MoveCondition::UsedWithin { move_idx: CH_ECHO_IDX, turns: 1 }, MoveRule::Fixed(CH_BLOOD_SHOTS_IDX)),
+                    (MoveCondition::Always, MoveRule::Fixed(CH_ECHO_IDX)),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::AcidSlimeS,
+        kind: MonsterKind::Normal,
+        health: &[(0, (8, 12)), (7, (9, 13))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Tackle",
+                tiers: &[(0, &[(&AS_TACKLE_3, Intent::attack(3, 1))]), (2, &[(&AS_TACKLE_4, Intent::attack(4, 1))])],
+            },
+            MoveDef { name: "Lick", tiers: &[(0, &[(&AS_LICK_1, Intent::debuff())])] },
+        ],
+        // Alternates after a random opening, which is always Lick from A17
+        rule: &[
+            (
+                0,
+                MoveRule::Branch {
+                    branches: &[
+                        (
+                            MoveCondition::TurnBelow { turns: 1 },
+                            MoveRule::Weighted {
+                                opening: &[],
+                                rolls: &[
+                                    MoveRoll { move_idx: AS_S_TACKLE_IDX, weight: 1, max_in_a_row: usize::MAX },
+                                    MoveRoll { move_idx: AS_S_LICK_IDX, weight: 1, max_in_a_row: usize::MAX },
+                                ],
+                            },
+                        ),
+                        (
+                            MoveCondition::Always,
+                            MoveRule::Sequence { opening: &[], cycle: &[AS_S_LICK_IDX, AS_S_TACKLE_IDX] },
+                        ),
+                    ],
+                },
+            ),
+            (17, MoveRule::Sequence { opening: &[], cycle: &[AS_S_LICK_IDX, AS_S_TACKLE_IDX] }),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::AcidSlimeM,
+        kind: MonsterKind::Normal,
+        health: &[(0, (28, 32)), (7, (29, 34))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Corrosive Spit",
+                tiers: &[
+                    (0, &[(&AS_CORROSIVE_SPIT_7, Intent::attack_debuff(7, 1))]),
+                    (2, &[(&AS_CORROSIVE_SPIT_8, Intent::attack_debuff(8, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Tackle",
+                tiers: &[(0, &[(&AS_TACKLE_10, Intent::attack(10, 1))]), (2, &[(&AS_TACKLE_12, Intent::attack(12, 1))])],
+            },
+            MoveDef { name: "Lick", tiers: &[(0, &[(&AS_LICK_1, Intent::debuff())])] },
+        ],
+        rule: &[(0, AS_ROLLS), (17, AS_ROLLS_A17)],
+    },
+    MonsterDef {
+        name: MonsterName::AcidSlimeL,
+        kind: MonsterKind::Normal,
+        health: &[(0, (65, 69)), (7, (68, 72))],
+        modifiers: &[(0, &[(ModifierKind::Split, (1, 1))])],
+        moves: &[
+            MoveDef {
+                name: "Corrosive Spit",
+                tiers: &[
+                    (0, &[(&AS_CORROSIVE_SPIT_11, Intent::attack_debuff(11, 1))]),
+                    (2, &[(&AS_CORROSIVE_SPIT_12, Intent::attack_debuff(12, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Tackle",
+                tiers: &[(0, &[(&AS_TACKLE_16, Intent::attack(16, 1))]), (2, &[(&AS_TACKLE_18, Intent::attack(18, 1))])],
+            },
+            MoveDef { name: "Lick", tiers: &[(0, &[(&AS_LICK_2, Intent::debuff())])] },
+            MoveDef { name: "Split", tiers: &[(0, &[(&AS_SPLIT, Intent::unknown())])] },
+        ],
+        rule: &[
+            (
+                0,
+                MoveRule::Branch {
+                    branches: &[(SLIME_SPLIT, MoveRule::Fixed(AS_SPLIT_IDX)), (MoveCondition::Always, AS_ROLLS)],
+                },
+            ),
+            (
+                17,
+                MoveRule::Branch {
+                    branches: &[(SLIME_SPLIT, MoveRule::Fixed(AS_SPLIT_IDX)), (MoveCondition::Always, AS_ROLLS_A17)],
+                },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::SpikeSlimeS,
+        kind: MonsterKind::Normal,
+        health: &[(0, (10, 14)), (7, (11, 15))],
+        modifiers: &[(0, &[])],
+        moves: &[MoveDef {
+            name: "Tackle",
+            tiers: &[(0, &[(&SS_TACKLE_5, Intent::attack(5, 1))]), (2, &[(&SS_TACKLE_6, Intent::attack(6, 1))])],
+        }],
+        rule: &[(0, MoveRule::Fixed(0))],
+    },
+    MonsterDef {
+        name: MonsterName::SpikeSlimeM,
+        kind: MonsterKind::Normal,
+        health: &[(0, (28, 32)), (7, (29, 34))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Flame Tackle",
+                tiers: &[
+                    (0, &[(&SS_FLAME_TACKLE_8, Intent::attack_debuff(8, 1))]),
+                    (2, &[(&SS_FLAME_TACKLE_10, Intent::attack_debuff(10, 1))]),
+                ],
+            },
+            MoveDef { name: "Lick", tiers: &[(0, &[(&SS_LICK_1, Intent::debuff())])] },
+        ],
+        rule: &[(0, SS_ROLLS), (17, SS_ROLLS_A17)],
+    },
+    MonsterDef {
+        name: MonsterName::SpikeSlimeL,
+        kind: MonsterKind::Normal,
+        health: &[(0, (64, 70)), (7, (67, 73))],
+        modifiers: &[(0, &[(ModifierKind::Split, (1, 1))])],
+        moves: &[
+            MoveDef {
+                name: "Flame Tackle",
+                tiers: &[
+                    (0, &[(&SS_FLAME_TACKLE_16, Intent::attack_debuff(16, 1))]),
+                    (2, &[(&SS_FLAME_TACKLE_18, Intent::attack_debuff(18, 1))]),
+                ],
+            },
+            MoveDef {
+                name: "Lick",
+                tiers: &[(0, &[(&SS_LICK_2, Intent::debuff())]), (17, &[(&SS_LICK_3, Intent::debuff())])],
+            },
+            MoveDef { name: "Split", tiers: &[(0, &[(&SS_SPLIT, Intent::unknown())])] },
+        ],
+        rule: &[
+            (
+                0,
+                MoveRule::Branch {
+                    branches: &[(SLIME_SPLIT, MoveRule::Fixed(SS_SPLIT_IDX)), (MoveCondition::Always, SS_ROLLS)],
+                },
+            ),
+            (
+                17,
+                MoveRule::Branch {
+                    branches: &[(SLIME_SPLIT, MoveRule::Fixed(SS_SPLIT_IDX)), (MoveCondition::Always, SS_ROLLS_A17)],
+                },
+            ),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::SlimeBoss,
+        kind: MonsterKind::Boss,
+        health: &[(0, (140, 140)), (9, (150, 150))],
+        modifiers: &[(0, &[(ModifierKind::Split, (1, 1))])],
+        moves: &[
+            MoveDef {
+                name: "Goop Spray",
+                tiers: &[(0, &[(&SB_GOOP_SPRAY_3, Intent::strong_debuff())]), (19, &[(&SB_GOOP_SPRAY_5, Intent::strong_debuff())])],
+            },
+            MoveDef { name: "Preparing", tiers: &[(0, &[(&[], Intent::unknown())])] },
+            MoveDef {
+                name: "Slam",
+                tiers: &[(0, &[(&SB_SLAM_35, Intent::attack(35, 1))]), (4, &[(&SB_SLAM_38, Intent::attack(38, 1))])],
+            },
+            MoveDef { name: "Split", tiers: &[(0, &[(&SB_SPLIT, Intent::unknown())])] },
+        ],
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (SLIME_SPLIT, MoveRule::Fixed(SB_SPLIT_IDX)),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Cycle { opening: &[], cycle: &[SB_GOOP_SPRAY_IDX, SB_PREPARING_IDX, SB_SLAM_IDX] },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::MadGremlin,
+        kind: MonsterKind::Normal,
+        health: &[(0, (20, 24)), (7, (21, 25))],
+        modifiers: &[(0, &[(ModifierKind::Angry, (1, 1))]), (17, &[(ModifierKind::Angry, (2, 2))])],
+        moves: &[MoveDef {
+            name: "Scratch",
+            tiers: &[(0, &[(&MG_SCRATCH_4, Intent::attack(4, 1))]), (2, &[(&MG_SCRATCH_5, Intent::attack(5, 1))])],
+        }],
+        rule: &[(0, MoveRule::Fixed(0))],
+    },
+    MonsterDef {
+        name: MonsterName::SneakyGremlin,
+        kind: MonsterKind::Normal,
+        health: &[(0, (10, 14)), (7, (11, 15))],
+        modifiers: &[(0, &[])],
+        moves: &[MoveDef {
+            name: "Puncture",
+            tiers: &[(0, &[(&SG_PUNCTURE_9, Intent::attack(9, 1))]), (2, &[(&SG_PUNCTURE_10, Intent::attack(10, 1))])],
+        }],
+        rule: &[(0, MoveRule::Fixed(0))],
+    },
+    MonsterDef {
+        name: MonsterName::FatGremlin,
+        kind: MonsterKind::Normal,
+        health: &[(0, (13, 17)), (7, (14, 18))],
+        modifiers: &[(0, &[])],
+        moves: &[MoveDef {
+            name: "Smash",
+            tiers: &[
+                (0, &[(&FG_SMASH_4, Intent::attack_debuff(4, 1))]),
+                (2, &[(&FG_SMASH_5, Intent::attack_debuff(5, 1))]),
+                (17, &[(&FG_SMASH_5_FRAIL, Intent::attack_debuff(5, 1))]),
+            ],
+        }],
+        rule: &[(0, MoveRule::Fixed(0))],
+    },
+    MonsterDef {
+        name: MonsterName::ShieldGremlin,
+        kind: MonsterKind::Normal,
+        health: &[(0, (12, 15)), (7, (13, 17))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Protect",
+                tiers: &[
+                    (0, &[(&SHG_PROTECT_7, Intent::defend())]),
+                    (7, &[(&SHG_PROTECT_8, Intent::defend())]),
+                    (17, &[(&SHG_PROTECT_11, Intent::defend())]),
+                ],
+            },
+            MoveDef {
+                name: "Shield Bash",
+                tiers: &[(0, &[(&SHG_SHIELD_BASH_6, Intent::attack(6, 1))]), (2, &[(&SHG_SHIELD_BASH_8, Intent::attack(8, 1))])],
+            },
+        ],
+        // Shields its allies while it has any
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::AlliesBelow { count: 1 }, MoveRule::Fixed(SHG_SHIELD_BASH_IDX)),
+                    (MoveCondition::Always, MoveRule::Fixed(SHG_PROTECT_IDX)),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::GremlinWizard,
+        kind: MonsterKind::Normal,
+        health: &[(0, (21, 25)), (7, (22, 26))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef { name: "Charging", tiers: &[(0, &[(&[], Intent::unknown())])] },
+            MoveDef {
+                name: "Ultimate Blast",
+                tiers: &[
+                    (0, &[(&GW_ULTIMATE_BLAST_25, Intent::attack(25, 1))]),
+                    (2, &[(&GW_ULTIMATE_BLAST_30, Intent::attack(30, 1))]),
+                ],
+            },
+        ],
+        // Charges up again after every blast, except from A17
+        rule: &[
+            (0, MoveRule::Cycle { opening: &[], cycle: &[GW_CHARGING_IDX, GW_CHARGING_IDX, GW_ULTIMATE_BLAST_IDX] }),
+            (17, MoveRule::Cycle { opening: &[GW_CHARGING_IDX, GW_CHARGING_IDX], cycle: &[GW_ULTIMATE_BLAST_IDX] }),
+        ],
+    },
+    MonsterDef {
+        name: MonsterName::GremlinLeader,
+        kind: MonsterKind::Elite,
+        health: &[(0, (140, 148)), (8, (145, 155))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef { name: "Rally!", tiers: &[(0, &[(&GL_RALLY, Intent::unknown())])] },
+            MoveDef {
+                name: "Encourage",
+                tiers: &[
+                    (0, &[(&GL_ENCOURAGE_3_6, Intent::defend_buff())]),
+                    (3, &[(&GL_ENCOURAGE_4_6, Intent::defend_buff())]),
+                    (18, &[(&GL_ENCOURAGE_5_10, Intent::defend_buff())]),
+                ],
+            },
+            MoveDef { name: "Stab", tiers: &[(0, &[(&GL_STAB, Intent::attack(6, 3))])] },
+        ],
+        // Rallies more gremlins the fewer it has left
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::AlliesBelow { count: 1 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: GL_RALLY_IDX, weight: 75, max_in_a_row: 1 },
+                                MoveRoll { move_idx: GL_STAB_IDX, weight: 24, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::AlliesBelow { count: 2 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: GL_RALLY_IDX, weight: 50, max_in_a_row: 1 },
+                                MoveRoll { move_idx: GL_ENCOURAGE_IDX, weight: 30, max_in_a_row: 1 },
+                                MoveRoll { move_idx: GL_STAB_IDX, weight: 19, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: GL_ENCOURAGE_IDX, weight: 66, max_in_a_row: 1 },
+                                MoveRoll { move_idx: GL_STAB_IDX, weight: 33, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Reptomancer,
+        kind: MonsterKind::Elite,
+        health: &[(0, (180, 190)), (8, (190, 200))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Summon",
+                tiers: &[(0, &[(&RM_SUMMON_1, Intent::unknown())]), (18, &[(&RM_SUMMON_2, Intent::unknown())])],
+            },
+            MoveDef {
+                name: "Snake Strike",
+                tiers: &[
+                    (0, &[(&RM_SNAKE_STRIKE_13, Intent::attack_debuff(13, 2))]),
+                    (3, &[(&RM_SNAKE_STRIKE_16, Intent::attack_debuff(16, 2))]),
+                ],
+            },
+            MoveDef {
+                name: "Big Bite",
+                tiers: &[(0, &[(&RM_BIG_BITE_30, Intent::attack(30, 1))]), (3, &[(&RM_BIG_BITE_34, Intent::attack(34, 1))])],
+            },
+        ],
+        // Summons only while it has room for more daggers
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (MoveCondition::TurnBelow { turns: 1 }, MoveRule::Fixed(RM_SUMMON_IDX)),
+                    (
+                        MoveCondition::AlliesBelow { count: RM_DAGGERS_MAX },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: RM_SNAKE_STRIKE_IDX, weight: 33, max_in_a_row: 1 },
+                                MoveRoll { move_idx: RM_SUMMON_IDX, weight: 33, max_in_a_row: 2 },
+                                MoveRoll { move_idx: RM_BIG_BITE_IDX, weight: 33, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                    (
+                        MoveCondition::Always,
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: RM_SNAKE_STRIKE_IDX, weight: 33, max_in_a_row: 1 },
+                                MoveRoll { move_idx: RM_BIG_BITE_IDX, weight: 33, max_in_a_row: 1 },
+                            ],
+                        },
+                    ),
+                ],
+            },
+        )],
+    },
+    MonsterDef {
+        name: MonsterName::Dummy,
+        kind: MonsterKind::Normal,
+        health: &[(0, (50, 50))],
+        modifiers: &[(0, &[])],
+        moves: &[
+            MoveDef {
+                name: "Attack",
+                tiers: &[(
+                    0,
+                    &[(&[EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character }], Intent::attack(12, 1))],
+                )],
+            },
+            MoveDef {
+                name: "Defend",
+                tiers: &[(0, &[(&[EffectTemplate::BlockGain { amount: 12, target: TargetKind::Source }], Intent::defend())])],
+            },
+        ],
+        // Alternates after a random opening
+        rule: &[(
+            0,
+            MoveRule::Branch {
+                branches: &[
+                    (
+                        MoveCondition::TurnBelow { turns: 1 },
+                        MoveRule::Weighted {
+                            opening: &[],
+                            rolls: &[
+                                MoveRoll { move_idx: 0, weight: 1, max_in_a_row: usize::MAX },
+                                MoveRoll { move_idx: 1, weight: 1, max_in_a_row: usize::MAX },
+                            ],
+                        },
+                    ),
+                    (MoveCondition::Always, MoveRule::Sequence { opening: &[], cycle: &[0, 1] }),
+                ],
+            },
+        )],
+    },
+];
+
+/// Definitions loaded at runtime take precedence over the built-in ones.
+pub fn monster_def(name: MonsterName) -> Option<&'static MonsterDef> {
+    registry::monster_loaded(name).or_else(|| MONSTER_DEFS.iter().find(|d| d.name == name))
 }
 
-fn spawn_the_guardian(asc: u8) -> Monster {
-    let hp: u16 = if asc < 9 { 240 } else { 250 };
+fn tier<T>(tiers: Tiers<T>, ascension: u8) -> &'static T {
+    &tiers
+        .iter()
+        .rev()
+        .find(|(min, _)| ascension >= *min)
+        .expect("tiers start at ascension 0")
+        .1
+}
 
-    let fierce_effects: &'static [EffectTemplate] = if asc < 4 { &TG_FIERCE_BASH_32 } else { &TG_FIERCE_BASH_36 };
-    let fierce_damage = if asc < 4 { 32 } else { 36 };
+// ---------------------------------------------------------------------------
+// Spawning
+// ---------------------------------------------------------------------------
 
-    let defensive_effects: &'static [EffectTemplate] = if asc < 19 { &TG_DEFENSIVE_MODE_3 } else { &TG_DEFENSIVE_MODE_4 };
-    let roll_effects: &'static [EffectTemplate] = if asc < 4 { &TG_ROLL_ATTACK_9 } else { &TG_ROLL_ATTACK_10 };
-    let roll_damage = if asc < 4 { 9 } else { 10 };
+pub fn spawn_monster(name: MonsterName, ascension: u8, rng: &mut impl Rng) -> Monster {
+    let def = monster_def(name).unwrap_or_else(|| unreachable!("{name:?} has no monster definition"));
+    spawn_from_def(def, ascension, rng)
+}
 
-    let mode_shift_stacks: i16 = if asc < 9 { 30 } else if asc < 19 { 35 } else { 40 };
+fn spawn_from_def(def: &MonsterDef, asc: u8, rng: &mut impl Rng) -> Monster {
+    let (hmin, hmax) = *tier(def.health, asc);
+    let hp = if hmin == hmax { hmin } else { rng.random_range(hmin..=hmax) };
 
     let mut vitals = vitals_new(hp, hp);
-    modifier_apply(&mut vitals.modifiers, ModifierKind::ModeShift, mode_shift_stacks);
-    vitals.modifiers.is_new[ModifierKind::ModeShift as usize] = false;
+    for &(kind, (smin, smax)) in *tier(def.modifiers, asc) {
+        let stacks = if smin == smax { smin } else { rng.random_range(smin..=smax) };
+        modifier_apply(&mut vitals.modifiers, kind, stacks);
+        modifier_set_old(&mut vitals.modifiers, kind);
+    }
 
     Monster {
-        name: MonsterName::TheGuardian,
-        kind: MonsterKind::Boss,
+        name: def.name,
+        kind: def.kind,
         vitals,
-        moves: vec![
-            Move { name: "Charging Up", effects: &TG_CHARGING_UP, intent: Intent::block_only() },
-            Move { name: "Fierce Bash", effects: fierce_effects, intent: Intent::attack(fierce_damage, 1) },
-            Move { name: "Vent Steam", effects: &TG_VENT_STEAM, intent: Intent::debuff() },
-            Move { name: "Whirlwind", effects: &TG_WHIRLWIND, intent: Intent::attack(5, 4) },
-            Move { name: "Defensive Mode", effects: defensive_effects, intent: Intent::buff() },
-            Move { name: "Roll Attack", effects: roll_effects, intent: Intent::attack(roll_damage, 1) },
-            Move { name: "Twin Slam", effects: &TG_TWIN_SLAM, intent: Intent::attack(8, 2) },
-        ],
+        moves: def
+            .moves
+            .iter()
+            .map(|m| {
+                let variants = *tier(m.tiers, asc);
+                let (effects, intent) =
+                    if variants.len() == 1 { variants[0] } else { variants[rng.random_range(0..variants.len())] };
+                Move { name: m.name, effects, intent }
+            })
+            .collect(),
         move_current: None,
         move_history: Vec::new(),
+        alive: true,
     }
 }
 
-fn spawn_dummy() -> Monster {
-    Monster {
-        name: MonsterName::Dummy,
-        kind: MonsterKind::Normal,
-        vitals: vitals_new(50, 50),
-        moves: vec![
-            Move { name: "Attack", effects: &[EffectTemplate::DamagePhysical { base: 12, target: TargetKind::Character }], intent: Intent::attack(12, 1) },
-            Move { name: "Defend", effects: &[EffectTemplate::BlockGain { amount: 12, target: TargetKind::Source }], intent: Intent::block_only() },
-        ],
-        move_current: None,
-        move_history: Vec::new(),
-    }
+/// Maximum HP the Corrupt Heart can lose per turn.
+pub fn heart_invincible_cap(asc: u8) -> i16 {
+    if asc < 19 { 300 } else { 200 }
 }
 
 // ---------------------------------------------------------------------------
 // Move selection
 // ---------------------------------------------------------------------------
 
-pub fn select_next_move(monster: &Monster, _ascension: u8, rng: &mut impl Rng) -> usize {
-    match monster.name {
-        MonsterName::JawWorm => jaw_worm_next_move(monster, rng),
-        MonsterName::Cultist => cultist_next_move(monster),
-        MonsterName::FungiBeast => fungi_beast_next_move(monster, rng),
-        MonsterName::LouseGreen => louse_green_next_move(monster, rng),
-        MonsterName::TheGuardian => guardian_next_move(monster),
-        MonsterName::Dummy => dummy_next_move(monster, rng),
-    }
-}
-
-fn last_move(monster: &Monster) -> Option<usize> {
-    monster.move_history.last().copied()
-}
-
-fn last_n_moves(monster: &Monster, n: usize) -> Option<&[usize]> {
-    let h = &monster.move_history;
-    if h.len() >= n {
-        Some(&h[h.len() - n..])
-    } else {
-        None
-    }
+/// `allies` is the number of other living monsters (summoners and protectors care).
+pub fn select_next_move(monster: &Monster, ascension: u8, allies: usize, rng: &mut impl Rng) -> usize {
+    let def = monster_def(monster.name).unwrap_or_else(|| unreachable!("{:?} has no monster definition", monster.name));
+    next_move_from_rule(tier(def.rule, ascension), monster, allies, rng)
 }
 
-fn jaw_worm_next_move(monster: &Monster, rng: &mut impl Rng) -> usize {
-    if monster.move_current.is_none() {
-        return JW_CHOMP_IDX;
-    }
-
-    let num: u32 = rng.random_range(0..99);
-    if num < 25 {
-        if last_move(monster) == Some(JW_CHOMP_IDX) {
-            return if rng.random_bool(0.5625) { JW_BELLOW_IDX } else { JW_THRASH_IDX };
+fn next_move_from_rule(rule: &MoveRule, monster: &Monster, allies: usize, rng: &mut impl Rng) -> usize {
+    let turn = monster.move_history.len();
+    match *rule {
+        MoveRule::Fixed(idx) => idx,
+        MoveRule::Cycle { opening, cycle } => match opening.get(turn) {
+            Some(&idx) => idx,
+            None => cycle[(turn - opening.len()) % cycle.len()],
+        },
+        MoveRule::Sequence { opening, cycle } => {
+            let order = || opening.iter().chain(cycle);
+            match last_move(monster).and_then(|last| order().position(|&m| m == last)) {
+                Some(i) if i + 1 < opening.len() + cycle.len() => *order().nth(i + 1).unwrap(),
+                Some(_) => cycle[0],
+                None => *order().next().unwrap(),
+            }
         }
-        return JW_CHOMP_IDX;
-    } else if num < 55 {
-        if last_n_moves(monster, 2) == Some(&[JW_THRASH_IDX, JW_THRASH_IDX]) {
-            return if rng.random_bool(0.357) { JW_CHOMP_IDX } else { JW_BELLOW_IDX };
+        MoveRule::Weighted { opening, rolls } => {
+            if let Some(&idx) = opening.get(turn) {
+                return idx;
+            }
+
+            let rolled = roll_weighted(rolls.iter(), rng).unwrap();
+            let allowed = |r: &&MoveRoll| {
+                last_n_moves(monster, r.max_in_a_row).is_none_or(|h| h.iter().any(|&m| m != r.move_idx))
+            };
+            if allowed(&rolled) {
+                return rolled.move_idx;
+            }
+            roll_weighted(rolls.iter().filter(allowed), rng).unwrap_or(rolled).move_idx
         }
-        return JW_THRASH_IDX;
-    } else {
-        if last_move(monster) == Some(JW_BELLOW_IDX) {
-            return if rng.random_bool(0.416) { JW_CHOMP_IDX } else { JW_THRASH_IDX };
+        MoveRule::Branch { branches } => {
+            let (_, rule) = branches
+                .iter()
+                .find(|(condition, _)| condition_holds(condition, monster, allies))
+                .expect("the last branch is Always");
+            next_move_from_rule(rule, monster, allies, rng)
         }
-        return JW_BELLOW_IDX;
     }
 }
 
-fn cultist_next_move(monster: &Monster) -> usize {
-    if monster.move_current.is_none() {
-        CULT_INCANT
-    } else {
-        CULT_DARK
+/// Picks one roll by weight; a single candidate needs no roll.
+fn roll_weighted<'a>(rolls: impl Iterator<Item = &'a MoveRoll> + Clone, rng: &mut impl Rng) -> Option<&'a MoveRoll> {
+    let total: u32 = rolls.clone().map(|r| r.weight).sum();
+    if rolls.clone().nth(1).is_none() || total == 0 {
+        return rolls.clone().next();
     }
+    let mut num = rng.random_range(0..total);
+    rolls.clone().find(|r| {
+        let hit = num < r.weight;
+        num = num.saturating_sub(r.weight);
+        hit
+    })
 }
 
-fn fungi_beast_next_move(monster: &Monster, rng: &mut impl Rng) -> usize {
-    let num: u32 = rng.random_range(0..99);
-    if num < 60 {
-        if last_n_moves(monster, 2) == Some(&[FB_BITE_IDX, FB_BITE_IDX]) {
-            return FB_GROW_IDX;
-        }
-        return FB_BITE_IDX;
-    }
-    if last_move(monster) == Some(FB_GROW_IDX) {
-        return FB_BITE_IDX;
+fn condition_holds(condition: &MoveCondition, monster: &Monster, allies: usize) -> bool {
+    let history = &monster.move_history;
+    let vitals = &monster.vitals;
+    match *condition {
+        MoveCondition::Always => true,
+        MoveCondition::TurnBelow { turns } => history.len() < turns,
+        MoveCondition::TurnSlot { every, slot } => history.len() % every == slot,
+        MoveCondition::UsedWithin { move_idx, turns } => history.iter().rev().take(turns).any(|&m| m == move_idx),
+        MoveCondition::UsedTimes { move_idx, times } => history.iter().filter(|&&m| m == move_idx).count() >= times,
+        MoveCondition::HasModifier { kind } => modifier_has(&vitals.modifiers, kind),
+        MoveCondition::LacksModifier { kind } => !modifier_has(&vitals.modifiers, kind),
+        MoveCondition::AlliesBelow { count } => allies < count,
+        MoveCondition::HealthBelowHalf => vitals.health < vitals.health_max / 2,
     }
-    FB_GROW_IDX
 }
 
-fn louse_green_next_move(monster: &Monster, rng: &mut impl Rng) -> usize {
-    let num: u32 = rng.random_range(0..99);
-    if num < 60 {
-        if last_n_moves(monster, 2) == Some(&[LG_BITE_IDX, LG_BITE_IDX]) {
-            return LG_GROW_IDX;
-        }
-        return LG_BITE_IDX;
-    }
-    if last_move(monster) == Some(LG_GROW_IDX) {
-        return LG_BITE_IDX;
-    }
-    LG_GROW_IDX
+fn last_move(monster: &Monster) -> Option<usize> {
+    monster.move_history.last().copied()
 }
 
-fn guardian_next_move(monster: &Monster) -> usize {
-    if monster.move_current.is_none() {
-        return TG_CHARGING;
-    }
-
-    let has_mode_shift = modifier_has(&monster.vitals.modifiers, ModifierKind::ModeShift);
-
-    if has_mode_shift {
-        // Offensive mode
-        match last_move(monster).unwrap() {
-            TG_CHARGING => TG_FIERCE,
-            TG_FIERCE => TG_VENT,
-            TG_VENT => TG_WHIRL,
-            TG_WHIRL => TG_CHARGING,
-            TG_TWIN => TG_WHIRL,
-            _ => unreachable!("invalid guardian move in offensive mode"),
-        }
+fn last_n_moves(monster: &Monster, n: usize) -> Option<&[usize]> {
+    let h = &monster.move_history;
+    if h.len() >= n {
+        Some(&h[h.len() - n..])
     } else {
-        // Defensive mode
-        let has_sharp_hide = modifier_has(&monster.vitals.modifiers, ModifierKind::SharpHide);
-        if has_sharp_hide {
-            match last_move(monster).unwrap() {
-                TG_DEFENSIVE => TG_ROLL,
-                TG_ROLL => TG_TWIN,
-                _ => unreachable!("invalid guardian move in defensive mode"),
-            }
-        } else {
-            TG_DEFENSIVE
-        }
-    }
-}
-
-fn dummy_next_move(monster: &Monster, rng: &mut impl Rng) -> usize {
-    if monster.move_current.is_none() {
-        return rng.random_range(0..2);
-    }
-    match last_move(monster).unwrap() {
-        0 => 1,
-        1 => 0,
-        _ => unreachable!(),
+        None
     }
 }
@@ -2,11 +2,17 @@
 
 use rand::Rng;
 
-use crate::cards::{REWARD_POOL_COMMON, REWARD_POOL_RARE, REWARD_POOL_UNCOMMON};
+use crate::action::relic_reward_next;
+use crate::cards::reward_pool;
 use crate::cards::get_card;
-use crate::effect::{Effect, EffectTemplate, SelectionKind, TargetKind};
+use crate::effect::{Condition, CountKind, DamageKind, Effect, EffectTemplate, PileKind, SelectionKind, TargetKind};
+use crate::encounter::{
+    ACT_FINAL, ACT_FIRST, ACT_LAST, ENCOUNTERS_WEAK, ENCOUNTERS_WEAK_ACT_1, Encounter, EncounterSlot, encounters_elite, encounters_normal,
+};
+use crate::map::{generate_map, generate_map_act_4};
 use crate::modifier::*;
 use crate::monster;
+use crate::relic::*;
 use crate::state::*;
 use crate::types::*;
 
@@ -20,11 +26,57 @@ pub const MAX_COMBAT_CARD_REWARD: usize = 3;
 pub const REST_SITE_HEAL_FACTOR: f32 = 0.30;
 pub const FACTOR_WEAK: f32 = 0.75;
 pub const FACTOR_VULN: f32 = 1.50;
+pub const FACTOR_FRAIL: f32 = 0.75;
 const CARD_REWARD_ROLL_OFFSET_BASE: i8 = 5;
 const CARD_REWARD_ROLL_OFFSET_MIN: i8 = -40;
 const CHANCE_RARE: i32 = 3;
 const CHANCE_UNCOMMON: i32 = 40;
 const MODE_SHIFT_INCREASE_PER_CYCLE: i16 = 10;
+const BUSTED_CROWN_CARD_REWARD_PENALTY: usize = 2;
+const CHEMICAL_X_BONUS: u16 = 2;
+const BOSS_RELIC_CHOICES: usize = 3;
+const BURNING_ELITE_HEALTH_FACTOR: f32 = 1.25;
+const MAX_MONSTERS_ALIVE: usize = 5;
+const ACT_HEAL_FACTOR_A5: f32 = 0.75;
+const CHANCE_UPGRADE_ACT_2: u32 = 25;
+const CHANCE_UPGRADE_ACT_3: u32 = 50;
+const BOSS_GOLD: u16 = 100;
+const BOSS_GOLD_VARIANCE: u16 = 5;
+const BOSS_GOLD_FACTOR_A13: f32 = 0.75;
+
+// Chest size odds (out of 100): small < 50, medium < 83, large otherwise
+const CHANCE_CHEST_SMALL: u32 = 50;
+const CHANCE_CHEST_MEDIUM: u32 = 83;
+
+// ---------------------------------------------------------------------------
+// Chest tables: (common %, uncommon %, gold %, gold amount); rare is the rest
+// ---------------------------------------------------------------------------
+
+struct ChestTable {
+    chance_common: u32,
+    chance_uncommon: u32,
+    chance_gold: u32,
+    gold: u16,
+}
+
+const CHEST_SMALL: ChestTable = ChestTable {
+    chance_common: 75,
+    chance_uncommon: 25,
+    chance_gold: 50,
+    gold: 25,
+};
+const CHEST_MEDIUM: ChestTable = ChestTable {
+    chance_common: 35,
+    chance_uncommon: 50,
+    chance_gold: 35,
+    gold: 50,
+};
+const CHEST_LARGE: ChestTable = ChestTable {
+    chance_common: 0,
+    chance_uncommon: 75,
+    chance_gold: 50,
+    gold: 75,
+};
 
 // ---------------------------------------------------------------------------
 // EffectResult
@@ -93,12 +145,32 @@ impl EffectResult {
 // ---------------------------------------------------------------------------
 
 pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
+    // Anything still queued for a monster that has since died fizzles
+    if involves_dead_monster(state, &effect) {
+        return EffectResult::empty();
+    }
+
     match effect {
         Effect::CardDraw { count } => process_card_draw(state, count),
         Effect::CardPlay { card_idx } => process_card_play(state, card_idx),
         Effect::CardDiscard { card_idx } => process_card_discard(state, card_idx),
         Effect::CardDiscardAll => process_card_discard_all(state),
+        Effect::CardDiscardRandom { count } => {
+            let effects = select_random_hand(state, count)
+                .into_iter()
+                .map(|card_idx| Effect::CardDiscard { card_idx })
+                .collect();
+            EffectResult::top(effects)
+        }
         Effect::CardExhaust { card_idx } => process_card_exhaust(state, card_idx),
+        Effect::CardExhaustRandom { count } => {
+            let effects = select_random_hand(state, count)
+                .into_iter()
+                .map(|card_idx| Effect::CardExhaust { card_idx })
+                .collect();
+            EffectResult::top(effects)
+        }
+        Effect::CardFetchRandom { pile } => process_card_fetch_random(state, pile),
         Effect::CardRemove { card_idx } => process_card_remove(state, card_idx),
         Effect::CardActiveSet { card_idx } => {
             state.card_active = Some(card_idx);
@@ -108,15 +180,62 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
             state.card_active = None;
             EffectResult::empty()
         }
+        Effect::CardReplay { card_idx, x } => {
+            let card = state.combat_cards[card_idx];
+            state.card_x = x;
+            let effects = instantiate_templates(card.effects, ActorId::Character, Some(card.name), state);
+            state.card_x = None;
+            EffectResult::top(effects)
+        }
         Effect::AddShivs { count } => process_add_shivs(state, count),
+        Effect::CardAdd { name, count, pile } => process_card_add(state, name, count, pile),
         Effect::CalculatedGamble => process_calculated_gamble(state),
         Effect::CardUpgrade { deck_idx } => process_card_upgrade(state, deck_idx),
+        Effect::CardUpgradeAll { name } => {
+            // Every copy in combat, whichever pile it sits in
+            for card in state.combat_cards.iter_mut().filter(|c| c.name == name && !c.upgraded) {
+                *card = get_card(name, true);
+            }
+            EffectResult::empty()
+        }
         Effect::CardRewardRoll => process_card_reward_roll(state),
         Effect::CardRewardSelect { reward_idx } => process_card_reward_select(state, reward_idx),
         Effect::CardRewardClear => {
             state.card_rewards.clear();
             EffectResult::empty()
         }
+        Effect::ChestOpen { size } => process_chest_open(state, size),
+        Effect::BossChestOpen => process_boss_chest_open(state),
+        Effect::RelicRewardSelect { reward_idx } => {
+            process_relic_reward_select(state, reward_idx)
+        }
+        Effect::RelicRewardClear => {
+            state.relic_rewards.clear();
+            EffectResult::empty()
+        }
+        Effect::KeyObtain { key } => {
+            match key {
+                KeyKind::Emerald => state.keys.emerald = true,
+                KeyKind::Ruby => state.keys.ruby = true,
+                KeyKind::Sapphire => state.keys.sapphire = true,
+            }
+            EffectResult::empty()
+        }
+        Effect::GoldGain { amount } => {
+            if !relic_has(state, RelicName::Ectoplasm) {
+                state.gold = state.gold.saturating_add(amount);
+            }
+            EffectResult::empty()
+        }
+        Effect::GoldSteal { source, amount } => {
+            // The thief carries the gold until it dies
+            let amount = amount.min(state.gold);
+            if amount > 0 {
+                state.gold -= amount;
+                modifier_apply(&mut vitals_mut(state, source).modifiers, ModifierKind::StolenGold, amount as i16);
+            }
+            EffectResult::empty()
+        }
         Effect::TargetSet { monster_idx } => {
             state.card_target = Some(monster_idx);
             EffectResult::empty()
@@ -129,7 +248,9 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
             source,
             target,
             base,
-        } => process_damage_physical(state, source, target, base),
+            kind,
+            card,
+        } => process_damage_physical(state, source, target, base, kind, card),
         Effect::DamageDeal { target, amount } => process_damage_deal(state, target, amount),
         Effect::HealthGain { target, amount } => process_health_gain(state, target, amount),
         Effect::HealthLoss { target, amount } => process_health_loss(state, target, amount),
@@ -143,7 +264,7 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
             EffectResult::empty()
         }
         Effect::EnergyGain { amount } => {
-            state.energy.current = (state.energy.current + amount).min(255);
+            state.energy.current = state.energy.current.saturating_add(amount);
             EffectResult::empty()
         }
         Effect::EnergyLoss { amount } => {
@@ -159,20 +280,50 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
             modifier_remove(&mut vitals_mut(state, target).modifiers, kind);
             EffectResult::empty()
         }
+        Effect::ModifierSet {
+            target,
+            kind,
+            stacks,
+        } => {
+            let mods = &mut vitals_mut(state, target).modifiers;
+            modifier_set_stacks(mods, kind, stacks);
+            EffectResult::empty()
+        }
+        Effect::ModifierMultiply {
+            target,
+            kind,
+            factor,
+        } => {
+            let mods = &mut vitals_mut(state, target).modifiers;
+            if modifier_has(mods, kind) {
+                let stacks = modifier_stacks(mods, kind);
+                modifier_apply(mods, kind, stacks * (factor - 1));
+            }
+            EffectResult::empty()
+        }
         Effect::ModifierTick { target } => {
             modifier_tick(&mut vitals_mut(state, target).modifiers);
             EffectResult::empty()
         }
         Effect::ModifierSetNotNew => process_modifier_set_not_new(state),
         Effect::Death { actor } => process_death(state, actor),
+        Effect::Escape { actor } => process_escape(state, actor),
         Effect::CombatStart => process_combat_start(state),
         Effect::CombatEnd => process_combat_end(state),
         Effect::TurnStart { actor } => process_turn_start(state, actor),
         Effect::TurnEnd { actor } => process_turn_end(state, actor),
+        Effect::MonsterMove { monster_idx } => process_monster_move(state, monster_idx),
+        Effect::MonsterSpawn {
+            name,
+            health,
+            minion,
+        } => process_monster_spawn(state, name, health, minion),
+        Effect::MonsterSplit { monster_idx, into } => process_monster_split(state, monster_idx, into),
         Effect::MonsterMoveUpdate { monster_idx } => {
             process_monster_move_update(state, monster_idx)
         }
         Effect::RoomEnter => process_room_enter(state),
+        Effect::ActAdvance => process_act_advance(state),
         Effect::GameEnd => {
             // Re-insert so determine_fsm can see it
             state.effect_queue.push_front(Effect::GameEnd);
@@ -186,7 +337,15 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
             state.effect_queue.push_front(Effect::AwaitCardReward);
             EffectResult::pause()
         }
+        Effect::AwaitRelicReward => {
+            state.effect_queue.push_front(Effect::AwaitRelicReward);
+            EffectResult::pause()
+        }
         Effect::AwaitDiscard => {
+            // Nothing to choose from
+            if state.hand.is_empty() {
+                return EffectResult::empty();
+            }
             state.effect_queue.push_front(Effect::AwaitDiscard);
             EffectResult::pause()
         }
@@ -197,18 +356,34 @@ pub fn process_effect(state: &mut GameState, effect: Effect) -> EffectResult {
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn vitals_mut(state: &mut GameState, actor: ActorId) -> &mut Vitals {
-    match actor {
-        ActorId::Character => &mut state.character.vitals,
-        ActorId::Monster(i) => &mut state.monsters[i as usize].vitals,
-    }
+fn involves_dead_monster(state: &GameState, effect: &Effect) -> bool {
+    involves_actor(effect, |a| !actor_alive(state, a))
 }
 
-fn vitals_ref(state: &GameState, actor: ActorId) -> &Vitals {
-    match actor {
-        ActorId::Character => &state.character.vitals,
-        ActorId::Monster(i) => &state.monsters[i as usize].vitals,
-    }
+fn involves_actor(effect: &Effect, pred: impl Fn(ActorId) -> bool) -> bool {
+    let actors: &[ActorId] = match effect {
+        Effect::DamagePhysical { source, target, .. } => &[*source, *target],
+        Effect::DamageDeal { target, .. }
+        | Effect::HealthGain { target, .. }
+        | Effect::HealthLoss { target, .. }
+        | Effect::BlockGain { target, .. }
+        | Effect::BlockSet { target, .. }
+        | Effect::ModifierGain { target, .. }
+        | Effect::ModifierRemove { target, .. }
+        | Effect::ModifierSet { target, .. }
+        | Effect::ModifierMultiply { target, .. }
+        | Effect::ModifierTick { target } => &[*target],
+        Effect::Death { actor }
+        | Effect::Escape { actor }
+        | Effect::TurnStart { actor }
+        | Effect::TurnEnd { actor } => &[*actor],
+        Effect::GoldSteal { source, .. } => &[*source],
+        Effect::MonsterMove { monster_idx }
+        | Effect::MonsterMoveUpdate { monster_idx }
+        | Effect::MonsterSplit { monster_idx, .. } => &[ActorId::Monster(*monster_idx)],
+        _ => &[],
+    };
+    actors.iter().any(|&a| pred(a))
 }
 
 fn remove_from_hand(state: &mut GameState, card_idx: usize) -> bool {
@@ -220,11 +395,13 @@ fn remove_from_hand(state: &mut GameState, card_idx: usize) -> bool {
     }
 }
 
-/// Instantiate an EffectTemplate into one or more runtime Effects.
+/// Instantiate an EffectTemplate into one or more runtime Effects. `card` is
+/// the card being played, if any, and travels with the damage it causes.
 fn instantiate_templates(
     templates: &[EffectTemplate],
     source: ActorId,
-    state: &GameState,
+    card: Option<CardName>,
+    state: &mut GameState,
 ) -> Vec<Effect> {
     let mut out = Vec::new();
     for tmpl in templates {
@@ -235,9 +412,27 @@ fn instantiate_templates(
                         source,
                         target: actor,
                         base,
+                        kind: DamageKind::Attack,
+                        card,
+                    });
+                }
+            }
+            EffectTemplate::DamagePhysicalPerHealth { divisor, target } => {
+                for actor in resolve_target_kind(target, source, state) {
+                    out.push(Effect::DamagePhysical {
+                        source,
+                        target: actor,
+                        base: vitals_ref(state, actor).health / divisor + 1,
+                        kind: DamageKind::Attack,
+                        card,
                     });
                 }
             }
+            EffectTemplate::DamageDeal { amount, target } => {
+                for actor in resolve_target_kind(target, source, state) {
+                    out.push(Effect::DamageDeal { target: actor, amount });
+                }
+            }
             EffectTemplate::BlockGain { amount, target } => {
                 for actor in resolve_target_kind(target, source, state) {
                     out.push(Effect::BlockGain {
@@ -247,6 +442,11 @@ fn instantiate_templates(
                     });
                 }
             }
+            EffectTemplate::HealthLoss { amount, target } => {
+                for actor in resolve_target_kind(target, source, state) {
+                    out.push(Effect::HealthLoss { target: actor, amount });
+                }
+            }
             EffectTemplate::ModifierGain {
                 kind,
                 stacks,
@@ -268,36 +468,197 @@ fn instantiate_templates(
                     });
                 }
             }
+            EffectTemplate::ModifierMultiply {
+                kind,
+                factor,
+                target,
+            } => {
+                for actor in resolve_target_kind(target, source, state) {
+                    out.push(Effect::ModifierMultiply {
+                        target: actor,
+                        kind,
+                        factor,
+                    });
+                }
+            }
             EffectTemplate::EnergyGain { amount } => {
                 out.push(Effect::EnergyGain { amount });
             }
+            EffectTemplate::EnergyLoss { amount } => {
+                out.push(Effect::EnergyLoss { amount });
+            }
             EffectTemplate::AddShivs { count } => {
                 out.push(Effect::AddShivs { count });
             }
             EffectTemplate::CardDraw { count } => {
                 out.push(Effect::CardDraw { count });
             }
-            EffectTemplate::CardDiscard { selection } => {
+            EffectTemplate::CardDiscard { count, selection } => {
                 match selection {
                     SelectionKind::Input => {
-                        out.push(Effect::AwaitDiscard);
+                        for _ in 0..count {
+                            out.push(Effect::AwaitDiscard);
+                        }
                     }
                     SelectionKind::Random => {
-                        // Random discard handled at processing time
-                        out.push(Effect::CardDiscardAll); // placeholder: actually random 1
-                        // We'll handle this properly in the discard handler
+                        // Picked when it resolves, from the hand at that point
+                        out.push(Effect::CardDiscardRandom { count });
                     }
                 }
             }
+            EffectTemplate::CardExhaustRandom { count } => {
+                out.push(Effect::CardExhaustRandom { count });
+            }
+            EffectTemplate::CardFetchRandom { pile } => {
+                out.push(Effect::CardFetchRandom { pile });
+            }
+            EffectTemplate::CardAdd { name, count, pile } => {
+                out.push(Effect::CardAdd { name, count, pile });
+            }
+            EffectTemplate::CardUpgradeAll { name } => {
+                out.push(Effect::CardUpgradeAll { name });
+            }
             EffectTemplate::CalculatedGamble => {
                 out.push(Effect::CalculatedGamble);
             }
+            EffectTemplate::If { condition, then } => {
+                if condition_holds(state, condition, source) {
+                    out.extend(instantiate_templates(then, source, card, state));
+                }
+            }
+            EffectTemplate::ForEach { count, each } => {
+                for _ in 0..count_value(state, count) {
+                    out.extend(instantiate_templates(each, source, card, state));
+                }
+            }
+            EffectTemplate::Scaled { count, bonus, each } => {
+                let n = count_value(state, count).saturating_add(bonus as u16);
+                if n > 0 {
+                    let scaled: Vec<EffectTemplate> = each.iter().map(|t| template_scaled(*t, n)).collect();
+                    out.extend(instantiate_templates(&scaled, source, card, state));
+                }
+            }
+            EffectTemplate::PlayableIf { .. } => {}
+            EffectTemplate::MonsterSummon { pool, count } => {
+                for _ in 0..count {
+                    let name = pool[state.rng.random_range(0..pool.len())];
+                    out.push(Effect::MonsterSpawn {
+                        name,
+                        health: None,
+                        minion: true,
+                    });
+                }
+            }
+            EffectTemplate::MonsterSplit { into } => {
+                if let ActorId::Monster(monster_idx) = source {
+                    out.push(Effect::MonsterSplit { monster_idx, into });
+                }
+            }
+            EffectTemplate::Suicide => {
+                out.push(Effect::Death { actor: source });
+            }
+            EffectTemplate::GoldSteal { amount } => {
+                out.push(Effect::GoldSteal { source, amount });
+            }
+            EffectTemplate::Escape => {
+                out.push(Effect::Escape { actor: source });
+            }
+            EffectTemplate::Recover { percent } => {
+                let vitals = vitals_ref(state, source);
+                out.extend(
+                    modifier_debuffs(&vitals.modifiers).map(|kind| Effect::ModifierRemove { target: source, kind }),
+                );
+                let goal = (vitals.health_max as u32 * percent as u32 / 100) as u16;
+                if goal > vitals.health {
+                    out.push(Effect::HealthGain {
+                        target: source,
+                        amount: goal - vitals.health,
+                    });
+                }
+            }
         }
     }
     out
 }
 
-fn resolve_target_kind(kind: TargetKind, source: ActorId, state: &GameState) -> Vec<ActorId> {
+pub fn condition_holds(state: &GameState, condition: Condition, source: ActorId) -> bool {
+    match condition {
+        Condition::TargetHas { kind } => {
+            let target = match source {
+                ActorId::Character => state.card_target.map(ActorId::Monster),
+                ActorId::Monster(_) => Some(ActorId::Character),
+            };
+            target.is_some_and(|t| modifier_has(&vitals_ref(state, t).modifiers, kind))
+        }
+        Condition::DiscardedThisTurn => state.combat_history.this_turn().cards_discarded > 0,
+        Condition::DrawPileEmpty => state.draw_pile.is_empty(),
+    }
+}
+
+/// X for an X-cost card played with the current energy.
+fn x_cost_amount(state: &GameState) -> u16 {
+    let bonus = if relic_has(state, RelicName::ChemicalX) { CHEMICAL_X_BONUS } else { 0 };
+    state.energy.current as u16 + bonus
+}
+
+pub fn count_value(state: &GameState, count: CountKind) -> u16 {
+    match count {
+        CountKind::AttacksPlayedThisTurn => state.combat_history.this_turn().cards_played.attack,
+        // Outside a play, what playing it now would give
+        CountKind::XCost => state.card_x.unwrap_or_else(|| x_cost_amount(state)),
+        CountKind::SkillsInHand => state
+            .hand
+            .iter()
+            .filter(|&&i| state.combat_cards[i].kind == CardKind::Skill)
+            .count() as u16,
+        CountKind::CardsInHand => state.hand.len() as u16,
+    }
+}
+
+/// The template with its amount multiplied by `n`, saturating at the field's
+/// range; those without one are unchanged.
+fn template_scaled(tmpl: EffectTemplate, n: u16) -> EffectTemplate {
+    let n_u8 = n.min(u8::MAX as u16) as u8;
+    let n_i16 = n.min(i16::MAX as u16) as i16;
+    match tmpl {
+        EffectTemplate::DamagePhysical { base, target } => EffectTemplate::DamagePhysical {
+            base: base.saturating_mul(n),
+            target,
+        },
+        EffectTemplate::DamageDeal { amount, target } => EffectTemplate::DamageDeal {
+            amount: amount.saturating_mul(n),
+            target,
+        },
+        EffectTemplate::BlockGain { amount, target } => EffectTemplate::BlockGain {
+            amount: amount.saturating_mul(n),
+            target,
+        },
+        EffectTemplate::HealthLoss { amount, target } => EffectTemplate::HealthLoss {
+            amount: amount.saturating_mul(n),
+            target,
+        },
+        EffectTemplate::ModifierGain { kind, stacks, target } => EffectTemplate::ModifierGain {
+            kind,
+            stacks: stacks.saturating_mul(n_i16),
+            target,
+        },
+        EffectTemplate::EnergyGain { amount } => EffectTemplate::EnergyGain {
+            amount: amount.saturating_mul(n_u8),
+        },
+        EffectTemplate::EnergyLoss { amount } => EffectTemplate::EnergyLoss {
+            amount: amount.saturating_mul(n_u8),
+        },
+        EffectTemplate::AddShivs { count } => EffectTemplate::AddShivs {
+            count: count.saturating_mul(n_u8),
+        },
+        EffectTemplate::CardDraw { count } => EffectTemplate::CardDraw {
+            count: count.saturating_mul(n_u8),
+        },
+        other => other,
+    }
+}
+
+fn resolve_target_kind(kind: TargetKind, source: ActorId, state: &mut GameState) -> Vec<ActorId> {
     match kind {
         TargetKind::CardTarget => {
             if let Some(mi) = state.card_target {
@@ -307,18 +668,140 @@ fn resolve_target_kind(kind: TargetKind, source: ActorId, state: &GameState) ->
             }
         }
         TargetKind::Character => vec![ActorId::Character],
-        TargetKind::AllMonsters => (0..state.monsters.len())
-            .map(|i| ActorId::Monster(i as u8))
+        TargetKind::AllMonsters => monster_ids_alive(state).map(ActorId::Monster).collect(),
+        TargetKind::RandomMonster => select_random_monster(state)
+            .map(ActorId::Monster)
+            .into_iter()
+            .collect(),
+        TargetKind::AllAllies => monster_ids_alive(state)
+            .map(ActorId::Monster)
+            .filter(|&actor| actor != source)
+            .collect(),
+        TargetKind::RandomAlly => select_random_ally(state, source)
+            .map(ActorId::Monster)
+            .into_iter()
             .collect(),
         TargetKind::Source => vec![source],
     }
 }
 
+// ---------------------------------------------------------------------------
+// Random selection (game RNG)
+// ---------------------------------------------------------------------------
+
+/// Up to `count` distinct cards from the hand.
+fn select_random_hand(state: &mut GameState, count: u8) -> Vec<usize> {
+    let mut candidates = state.hand.clone();
+    let mut selected = Vec::new();
+    while selected.len() < count as usize && !candidates.is_empty() {
+        let i = state.rng.random_range(0..candidates.len());
+        selected.push(candidates.swap_remove(i));
+    }
+    selected
+}
+
+fn select_random_monster(state: &mut GameState) -> Option<u8> {
+    let alive: Vec<u8> = monster_ids_alive(state).collect();
+    if alive.is_empty() {
+        return None;
+    }
+    Some(alive[state.rng.random_range(0..alive.len())])
+}
+
+/// A living monster other than `source`.
+fn select_random_ally(state: &mut GameState, source: ActorId) -> Option<u8> {
+    let allies: Vec<u8> = monster_ids_alive(state)
+        .filter(|&i| ActorId::Monster(i) != source)
+        .collect();
+    if allies.is_empty() {
+        return None;
+    }
+    Some(allies[state.rng.random_range(0..allies.len())])
+}
+
+/// Position of a random card in `pile`.
+fn select_random_pile(state: &mut GameState, pile: PileKind) -> Option<usize> {
+    let len = match pile {
+        PileKind::Draw => state.draw_pile.len(),
+        PileKind::Discard => state.discard_pile.len(),
+    };
+    if len == 0 {
+        return None;
+    }
+    Some(state.rng.random_range(0..len))
+}
+
+// ---------------------------------------------------------------------------
+// Modifier hooks
+// ---------------------------------------------------------------------------
+
+fn all_actors(state: &GameState) -> Vec<ActorId> {
+    let mut actors = vec![ActorId::Character];
+    actors.extend(monster_ids_alive(state).map(ActorId::Monster));
+    actors
+}
+
+/// Run one kind of plain hook for every active modifier on `actor`.
+fn run_hooks(
+    state: &GameState,
+    actor: ActorId,
+    select: fn(&ModifierHooks) -> Option<Hook>,
+) -> Vec<Effect> {
+    let mods = &vitals_ref(state, actor).modifiers;
+    let mut effects = Vec::new();
+    for kind in modifier_kinds(mods) {
+        if let Some(hook) = select(&modifier_def(kind).hooks) {
+            effects.extend(hook(state, actor, modifier_stacks(mods, kind)));
+        }
+    }
+    effects
+}
+
+fn hooks_card_played(
+    state: &GameState,
+    card_idx: usize,
+    select: fn(&ModifierHooks) -> Option<HookCardPlayed>,
+) -> Vec<Effect> {
+    let mut effects = Vec::new();
+    for actor in all_actors(state) {
+        let mods = &vitals_ref(state, actor).modifiers;
+        for kind in modifier_kinds(mods) {
+            if let Some(hook) = select(&modifier_def(kind).hooks) {
+                effects.extend(hook(state, actor, modifier_stacks(mods, kind), card_idx));
+            }
+        }
+    }
+    effects
+}
+
+fn hooks_attacked(state: &GameState, actor: ActorId, amount: u16) -> Vec<Effect> {
+    let mods = &vitals_ref(state, actor).modifiers;
+    let mut effects = Vec::new();
+    for kind in modifier_kinds(mods) {
+        if let Some(hook) = modifier_def(kind).hooks.on_attacked {
+            effects.extend(hook(state, actor, modifier_stacks(mods, kind), amount));
+        }
+    }
+    effects
+}
+
+fn hooks_damage_taken(state: &GameState, actor: ActorId, amount: u16) -> Vec<Effect> {
+    let mods = &vitals_ref(state, actor).modifiers;
+    let mut effects = Vec::new();
+    for kind in modifier_kinds(mods) {
+        if let Some(hook) = modifier_def(kind).hooks.on_damage_taken {
+            effects.extend(hook(state, actor, modifier_stacks(mods, kind), amount));
+        }
+    }
+    effects
+}
+
 // ---------------------------------------------------------------------------
 // Effect handlers
 // ---------------------------------------------------------------------------
 
 fn process_card_draw(state: &mut GameState, count: u8) -> EffectResult {
+    let mut effects = Vec::new();
     for _ in 0..count {
         if state.draw_pile.is_empty() {
             // Shuffle discard into draw
@@ -338,19 +821,25 @@ fn process_card_draw(state: &mut GameState, count: u8) -> EffectResult {
         let card_idx = state.draw_pile.remove(0);
         if state.hand.len() < MAX_SIZE_HAND {
             state.hand.push(card_idx);
+
+            // Triggers when drawn, e.g. Void
+            let card = state.combat_cards[card_idx];
+            effects.extend(instantiate_templates(card.on_draw, ActorId::Character, Some(card.name), state));
         } else {
             state.discard_pile.push(card_idx);
         }
     }
-    EffectResult::empty()
+    EffectResult::top(effects)
 }
 
 fn process_card_play(state: &mut GameState, card_idx: usize) -> EffectResult {
     let card = state.combat_cards[card_idx];
     let mut effects = Vec::new();
 
-    // Energy loss
-    effects.push(Effect::EnergyLoss { amount: card.cost });
+    // Energy loss; X is fixed now so copies of this play (Burst) reuse it
+    let amount = if card.x_cost { state.energy.current } else { card.cost };
+    effects.push(Effect::EnergyLoss { amount });
+    state.card_x = card.x_cost.then(|| x_cost_amount(state));
 
     // Exhaust vs power vs discard
     if card.exhaust {
@@ -358,68 +847,36 @@ fn process_card_play(state: &mut GameState, card_idx: usize) -> EffectResult {
     } else if card.kind == CardKind::Power {
         effects.push(Effect::CardRemove { card_idx });
     } else {
-        effects.push(Effect::CardDiscard { card_idx });
-    }
-
-    // After Image: gain block when playing a card
-    let char_mods = &state.character.vitals.modifiers;
-    if modifier_has(char_mods, ModifierKind::AfterImage) {
-        let stacks = modifier_stacks(char_mods, ModifierKind::AfterImage);
-        effects.push(Effect::BlockGain {
-            target: ActorId::Character,
-            amount: stacks as u16,
-            from_card: false,
-        });
-    }
-
-    // Thousand Cuts: deal damage to all monsters when playing a card
-    if modifier_has(char_mods, ModifierKind::ThousandCuts) {
-        let stacks = modifier_stacks(char_mods, ModifierKind::ThousandCuts);
-        for i in 0..state.monsters.len() {
-            effects.push(Effect::DamagePhysical {
-                source: ActorId::Character,
-                target: ActorId::Monster(i as u8),
-                base: stacks as u16,
-            });
-        }
+        // Moved directly so a played card doesn't count as discarded
+        remove_from_hand(state, card_idx);
+        state.discard_pile.push(card_idx);
     }
 
-    // Sharp Hide: if the card is an Attack, each monster with SharpHide damages character
-    if card.kind == CardKind::Attack {
-        for (_i, m) in state.monsters.iter().enumerate() {
-            if modifier_has(&m.vitals.modifiers, ModifierKind::SharpHide) {
-                let stacks = modifier_stacks(&m.vitals.modifiers, ModifierKind::SharpHide);
-                effects.push(Effect::DamageDeal {
-                    target: ActorId::Character,
-                    amount: stacks as u16,
-                });
-            }
-        }
-    }
+    // Modifier hooks on every actor before the card resolves (After Image, Thousand Cuts, Sharp Hide)
+    effects.extend(hooks_card_played(state, card_idx, |h| h.on_card_play));
 
     // Card's own effects (instantiate templates)
-    let card_effects = instantiate_templates(card.effects, ActorId::Character, state);
+    let card_effects = instantiate_templates(card.effects, ActorId::Character, Some(card.name), state);
     effects.extend(card_effects);
 
-    // Burst: double skill effects
-    if modifier_has(&state.character.vitals.modifiers, ModifierKind::Burst)
-        && card.kind == CardKind::Skill
-    {
-        let extra = instantiate_templates(card.effects, ActorId::Character, state);
-        effects.extend(extra);
-        effects.push(Effect::ModifierGain {
-            target: ActorId::Character,
-            kind: ModifierKind::Burst,
-            stacks: -1,
-        });
+    // Counted after its templates, which only see the cards played before it
+    if let Some(turn) = state.combat_history.this_turn_mut() {
+        turn.cards_played.add(card.kind);
     }
 
+    // And after it (Burst, Beat of Death, Enrage)
+    effects.extend(hooks_card_played(state, card_idx, |h| h.on_card_played));
+    state.card_x = None;
+
     EffectResult::top(effects)
 }
 
 fn process_card_discard(state: &mut GameState, card_idx: usize) -> EffectResult {
     remove_from_hand(state, card_idx);
     state.discard_pile.push(card_idx);
+    if let Some(turn) = state.combat_history.this_turn_mut() {
+        turn.cards_discarded += 1;
+    }
     EffectResult::empty()
 }
 
@@ -455,6 +912,42 @@ fn process_add_shivs(state: &mut GameState, count: u8) -> EffectResult {
     EffectResult::empty()
 }
 
+fn process_card_fetch_random(state: &mut GameState, pile: PileKind) -> EffectResult {
+    if state.hand.len() >= MAX_SIZE_HAND {
+        return EffectResult::empty();
+    }
+    if let Some(pos) = select_random_pile(state, pile) {
+        let card_idx = match pile {
+            PileKind::Draw => state.draw_pile.remove(pos),
+            PileKind::Discard => state.discard_pile.remove(pos),
+        };
+        state.hand.push(card_idx);
+    }
+    EffectResult::empty()
+}
+
+fn process_card_add(
+    state: &mut GameState,
+    name: CardName,
+    count: u8,
+    pile: PileKind,
+) -> EffectResult {
+    let card = get_card(name, false);
+    for _ in 0..count {
+        let idx = state.combat_cards.len();
+        state.combat_cards.push(card);
+        match pile {
+            // Shuffled into a random position
+            PileKind::Draw => {
+                let pos = state.rng.random_range(0..=state.draw_pile.len());
+                state.draw_pile.insert(pos, idx);
+            }
+            PileKind::Discard => state.discard_pile.push(idx),
+        }
+    }
+    EffectResult::empty()
+}
+
 fn process_calculated_gamble(state: &mut GameState) -> EffectResult {
     let num_cards = state.hand.len();
     EffectResult::top(vec![
@@ -471,22 +964,39 @@ fn process_card_upgrade(state: &mut GameState, deck_idx: usize) -> EffectResult
     EffectResult::empty()
 }
 
+fn boss_gold(state: &mut GameState) -> Effect {
+    let amount = state.rng.random_range(BOSS_GOLD - BOSS_GOLD_VARIANCE..=BOSS_GOLD + BOSS_GOLD_VARIANCE);
+    let amount = if state.ascension < 13 {
+        amount
+    } else {
+        (amount as f32 * BOSS_GOLD_FACTOR_A13).round() as u16
+    };
+    Effect::GoldGain { amount }
+}
+
 fn process_card_reward_roll(state: &mut GameState) -> EffectResult {
     state.card_rewards.clear();
     let mut rolled_names: Vec<CardName> = Vec::new();
 
-    for _ in 0..MAX_COMBAT_CARD_REWARD {
+    let num_rewards = if relic_has(state, RelicName::BustedCrown) {
+        MAX_COMBAT_CARD_REWARD - BUSTED_CROWN_CARD_REWARD_PENALTY
+    } else {
+        MAX_COMBAT_CARD_REWARD
+    };
+
+    for _ in 0..num_rewards {
         let roll = state.rng.random_range(0i32..99) + state.character.reward_roll_offset as i32;
 
-        let pool = if roll < CHANCE_RARE {
+        let rare = roll < CHANCE_RARE;
+        let pool = if rare {
             state.character.reward_roll_offset = CARD_REWARD_ROLL_OFFSET_BASE;
-            REWARD_POOL_RARE
+            reward_pool(CardRarity::Rare)
         } else if roll < CHANCE_UNCOMMON {
-            REWARD_POOL_UNCOMMON
+            reward_pool(CardRarity::Uncommon)
         } else {
             state.character.reward_roll_offset =
                 (state.character.reward_roll_offset - 1).max(CARD_REWARD_ROLL_OFFSET_MIN);
-            REWARD_POOL_COMMON
+            reward_pool(CardRarity::Common)
         };
 
         let mut name = pool[state.rng.random_range(0..pool.len())];
@@ -494,7 +1004,16 @@ fn process_card_reward_roll(state: &mut GameState) -> EffectResult {
             name = pool[state.rng.random_range(0..pool.len())];
         }
         rolled_names.push(name);
-        state.card_rewards.push(get_card(name, false));
+
+        // Later acts offer upgraded non-rare cards, half as often from A12
+        let chance_upgrade = match state.act {
+            ACT_FIRST => 0,
+            2 => CHANCE_UPGRADE_ACT_2,
+            _ => CHANCE_UPGRADE_ACT_3,
+        };
+        let chance_upgrade = if state.ascension < 12 { chance_upgrade } else { chance_upgrade / 2 };
+        let upgraded = !rare && state.rng.random_range(0..100) < chance_upgrade;
+        state.card_rewards.push(get_card(name, upgraded));
     }
 
     EffectResult::bot(vec![Effect::AwaitCardReward])
@@ -507,24 +1026,138 @@ fn process_card_reward_select(state: &mut GameState, reward_idx: usize) -> Effec
     EffectResult::empty()
 }
 
-fn process_damage_physical(
-    state: &mut GameState,
+fn process_chest_open(state: &mut GameState, size: ChestSize) -> EffectResult {
+    let table = match size {
+        ChestSize::Small => &CHEST_SMALL,
+        ChestSize::Medium => &CHEST_MEDIUM,
+        ChestSize::Large => &CHEST_LARGE,
+    };
+
+    let mut effects = Vec::new();
+    if state.rng.random_range(0..100) < table.chance_gold {
+        // Gold varies by +-10% around the chest's nominal amount
+        let gold_min = table.gold * 9 / 10;
+        let gold_max = table.gold * 11 / 10;
+        effects.push(Effect::GoldGain {
+            amount: state.rng.random_range(gold_min..=gold_max),
+        });
+    }
+
+    let roll = state.rng.random_range(0..100);
+    let rarity = if roll < table.chance_common {
+        RelicRarity::Common
+    } else if roll < table.chance_common + table.chance_uncommon {
+        RelicRarity::Uncommon
+    } else {
+        RelicRarity::Rare
+    };
+
+    state.relic_rewards.clear();
+    if let Some(name) = roll_relic(state, rarity) {
+        state.relic_rewards.push(name);
+    }
+
+    effects.push(relic_reward_await(state));
+    EffectResult::top(effects)
+}
+
+fn process_boss_chest_open(state: &mut GameState) -> EffectResult {
+    state.relic_rewards.clear();
+    for _ in 0..BOSS_RELIC_CHOICES {
+        if let Some(name) = roll_relic(state, RelicRarity::Boss) {
+            state.relic_rewards.push(name);
+        }
+    }
+    EffectResult::bot(vec![relic_reward_await(state)])
+}
+
+/// Wait for a relic choice, or move on when every pool the chest rolls from
+/// is exhausted.
+fn relic_reward_await(state: &mut GameState) -> Effect {
+    if state.relic_rewards.is_empty() {
+        relic_reward_next(state)
+    } else {
+        Effect::AwaitRelicReward
+    }
+}
+
+/// Roll an unowned relic of `rarity`, falling through to the other non-boss
+/// rarities when its pool is exhausted. Relics already offered in this reward
+/// are skipped.
+fn roll_relic(state: &mut GameState, rarity: RelicRarity) -> Option<RelicName> {
+    let order: &[RelicRarity] = match rarity {
+        RelicRarity::Common => &[RelicRarity::Common, RelicRarity::Uncommon, RelicRarity::Rare],
+        RelicRarity::Uncommon => &[RelicRarity::Uncommon, RelicRarity::Rare, RelicRarity::Common],
+        RelicRarity::Rare => &[RelicRarity::Rare, RelicRarity::Uncommon, RelicRarity::Common],
+        RelicRarity::Boss => &[RelicRarity::Boss],
+    };
+
+    for &rarity in order {
+        let available: Vec<RelicName> = relic_pool(rarity)
+            .iter()
+            .copied()
+            .filter(|r| !state.relics.contains(r) && !state.relic_rewards.contains(r))
+            .collect();
+        if !available.is_empty() {
+            return Some(available[state.rng.random_range(0..available.len())]);
+        }
+    }
+    None
+}
+
+fn process_relic_reward_select(state: &mut GameState, reward_idx: usize) -> EffectResult {
+    let name = state.relic_rewards[reward_idx];
+    state.relic_rewards.clear();
+    state.relics.push(name);
+
+    if relic_grants_energy(name) {
+        state.energy.max += 1;
+    }
+
+    let health_max_gain = relic_health_max_gain(name);
+    if health_max_gain > 0 {
+        state.character.vitals.health_max += health_max_gain;
+        return EffectResult::top(vec![Effect::HealthGain {
+            target: ActorId::Character,
+            amount: health_max_gain,
+        }]);
+    }
+
+    EffectResult::empty()
+}
+
+/// Attack damage after the source's and target's modifiers. `card` is the
+/// card that dealt it, for card-specific bonuses like Accuracy on Shivs.
+pub fn damage_attack_amount(
+    state: &GameState,
     source: ActorId,
     target: ActorId,
     base: u16,
-) -> EffectResult {
-    let source_mods = &vitals_ref(state, source).modifiers;
+    card: Option<CardName>,
+) -> u16 {
     let target_mods = &vitals_ref(state, target).modifiers;
 
+    let mut value = damage_attack_outgoing(state, source, base, card);
+
+    // Apply vulnerable
+    if modifier_has(target_mods, ModifierKind::Vulnerable) {
+        value *= FACTOR_VULN;
+    }
+
+    value as u16
+}
+
+/// Attack damage after the source's modifiers only, as a card shows it before
+/// a target is picked.
+pub fn damage_attack_outgoing(state: &GameState, source: ActorId, base: u16, card: Option<CardName>) -> f32 {
+    let source_mods = &vitals_ref(state, source).modifiers;
+
     let mut value = base as f32;
 
-    // Accuracy (only for shiv, but we apply based on whether source has accuracy and card is shiv)
-    // In the Python code, accuracy is checked when source is a card named "Shiv"
-    // Here we handle it in card_play by checking the card name. Accuracy bonus is applied
-    // to the base before DamagePhysical is created. For simplicity, we trust the base already
-    // includes accuracy if appropriate. The card_play handler should add accuracy to shiv damage.
-    // Actually, let's just add accuracy if the source is Character and has accuracy, and base <= 6
-    // No -- let's be precise. We need to know if this is from a shiv. We'll handle it upstream.
+    // Apply accuracy (Shivs only)
+    if card == Some(CardName::Shiv) && modifier_has(source_mods, ModifierKind::Accuracy) {
+        value += modifier_stacks(source_mods, ModifierKind::Accuracy) as f32;
+    }
 
     // Apply strength
     if modifier_has(source_mods, ModifierKind::Strength) {
@@ -536,25 +1169,58 @@ fn process_damage_physical(
         value *= FACTOR_WEAK;
     }
 
-    // Apply vulnerable
-    if modifier_has(target_mods, ModifierKind::Vulnerable) {
-        value *= FACTOR_VULN;
-    }
-
     // Apply double damage
     if modifier_has(source_mods, ModifierKind::DoubleDamage) {
         value *= 2.0;
     }
 
-    let final_damage = value as u16;
-    if final_damage > 0 {
-        EffectResult::top(vec![Effect::DamageDeal {
+    value.max(0.0)
+}
+
+fn process_damage_physical(
+    state: &mut GameState,
+    source: ActorId,
+    target: ActorId,
+    base: u16,
+    kind: DamageKind,
+    card: Option<CardName>,
+) -> EffectResult {
+    let final_damage = match kind {
+        DamageKind::Attack => damage_attack_amount(state, source, target, base, card),
+        DamageKind::Thorns => base,
+        DamageKind::HpLoss => {
+            return EffectResult::top(vec![Effect::HealthLoss {
+                target,
+                amount: base,
+            }]);
+        }
+    };
+    if final_damage == 0 {
+        return EffectResult::empty();
+    }
+
+    let mut effects = vec![Effect::DamageDeal {
+        target,
+        amount: final_damage,
+    }];
+
+    // Envenom: unblocked attack damage also poisons
+    let source_mods = &vitals_ref(state, source).modifiers;
+    let unblocked = final_damage.saturating_sub(vitals_ref(state, target).block);
+    if kind == DamageKind::Attack && modifier_has(source_mods, ModifierKind::Envenom) && unblocked > 0 {
+        effects.push(Effect::ModifierGain {
             target,
-            amount: final_damage,
-        }])
-    } else {
-        EffectResult::empty()
+            kind: ModifierKind::Poison,
+            stacks: modifier_stacks(source_mods, ModifierKind::Envenom),
+        });
     }
+
+    // Resolved right after the damage lands
+    if kind == DamageKind::Attack {
+        effects.extend(hooks_attacked(state, target, unblocked));
+    }
+
+    EffectResult::top(effects)
 }
 
 fn process_damage_deal(state: &mut GameState, target: ActorId, amount: u16) -> EffectResult {
@@ -580,37 +1246,35 @@ fn process_health_gain(state: &mut GameState, target: ActorId, amount: u16) -> E
 
 fn process_health_loss(state: &mut GameState, target: ActorId, amount: u16) -> EffectResult {
     let vitals = vitals_mut(state, target);
-    vitals.health = vitals.health.saturating_sub(amount);
 
-    let mut effects = Vec::new();
+    // Invincible: caps the health lost per turn
+    let mut amount = amount;
+    if modifier_has(&vitals.modifiers, ModifierKind::Invincible) {
+        let cap = modifier_stacks(&vitals.modifiers, ModifierKind::Invincible);
+        amount = amount.min(cap.max(0) as u16);
+        modifier_set_stacks(&mut vitals.modifiers, ModifierKind::Invincible, cap - amount as i16);
+    }
 
-    if vitals.health == 0 {
-        effects.push(Effect::Death { actor: target });
-    } else {
-        // Mode shift check
-        if modifier_has(&vitals.modifiers, ModifierKind::ModeShift) {
-            let new_stacks =
-                modifier_stacks(&vitals.modifiers, ModifierKind::ModeShift) - amount as i16;
-            if new_stacks < modifier_def(ModifierKind::ModeShift).min_stacks {
-                modifier_remove(
-                    &mut vitals_mut(state, target).modifiers,
-                    ModifierKind::ModeShift,
-                );
-                if let ActorId::Monster(i) = target {
-                    effects.push(Effect::MonsterMoveUpdate { monster_idx: i });
-                }
-            } else {
-                vitals_mut(state, target).modifiers.stacks[ModifierKind::ModeShift as usize] =
-                    new_stacks;
-            }
+    let lost = amount.min(vitals.health);
+    vitals.health -= lost;
+    let dead = vitals.health == 0;
+
+    // The last combat's history is kept for the rewards screen, not added to
+    let in_combat = !state.monsters.is_empty();
+    if let Some(turn) = state.combat_history.this_turn_mut().filter(|_| in_combat) {
+        match target {
+            ActorId::Character => turn.damage_taken += lost,
+            ActorId::Monster(_) => turn.damage_dealt += lost,
         }
     }
 
-    if effects.is_empty() {
-        EffectResult::empty()
+    let effects = if dead {
+        vec![Effect::Death { actor: target }]
     } else {
-        EffectResult::top(effects)
-    }
+        hooks_damage_taken(state, target, amount)
+    };
+
+    EffectResult::top(effects)
 }
 
 fn process_block_gain(
@@ -619,18 +1283,29 @@ fn process_block_gain(
     amount: u16,
     from_card: bool,
 ) -> EffectResult {
+    let value = if from_card { block_card_amount(state, target, amount) } else { amount };
+
     let vitals = vitals_mut(state, target);
+    vitals.block = (vitals.block + value).min(999);
+    EffectResult::empty()
+}
+
+/// Block from a card effect after the target's Dexterity and Frail.
+pub fn block_card_amount(state: &GameState, target: ActorId, amount: u16) -> u16 {
+    let mods = &vitals_ref(state, target).modifiers;
     let mut value = amount as i32;
 
-    // Apply dexterity (only from card effects)
-    if from_card && modifier_has(&vitals.modifiers, ModifierKind::Dexterity) {
-        value += modifier_stacks(&vitals.modifiers, ModifierKind::Dexterity) as i32;
+    // Apply dexterity
+    if modifier_has(mods, ModifierKind::Dexterity) {
+        value += modifier_stacks(mods, ModifierKind::Dexterity) as i32;
     }
 
-    if value > 0 {
-        vitals.block = (vitals.block + value as u16).min(999);
+    // Apply frail
+    if modifier_has(mods, ModifierKind::Frail) {
+        value = (value as f32 * FACTOR_FRAIL) as i32;
     }
-    EffectResult::empty()
+
+    value.max(0) as u16
 }
 
 fn process_modifier_gain(
@@ -644,21 +1319,25 @@ fn process_modifier_gain(
         return process_mode_shift_gain(state, target);
     }
 
-    // Handle negative stacks as loss (e.g., Burst -1)
-    if stacks < 0 {
+    // Handle negative stacks as loss (e.g., Burst -1). Modifiers that can go
+    // negative (Strength, Dexterity) are applied as-is instead.
+    if stacks < 0 && modifier_def(kind).min_stacks >= 0 {
         let mods = &mut vitals_mut(state, target).modifiers;
-        if modifier_has(mods, kind) {
-            let idx = kind as usize;
-            mods.stacks[idx] += stacks;
-            let cfg = modifier_def(kind);
-            if mods.stacks[idx] < cfg.min_stacks {
-                modifier_remove(mods, kind);
-            }
-        }
+        let left = modifier_stacks(mods, kind).saturating_add(stacks);
+        modifier_set_stacks(mods, kind, left);
         return EffectResult::empty();
     }
 
-    modifier_apply(&mut vitals_mut(state, target).modifiers, kind, stacks);
+    // Artifact negates a debuff; lowering Strength or Dexterity counts as one
+    let mods = &mut vitals_mut(state, target).modifiers;
+    let debuff = !modifier_def(kind).is_buff || stacks < 0;
+    if debuff && modifier_has(mods, ModifierKind::Artifact) {
+        let left = modifier_stacks(mods, ModifierKind::Artifact) - 1;
+        modifier_set_stacks(mods, ModifierKind::Artifact, left);
+        return EffectResult::empty();
+    }
+
+    modifier_apply(mods, kind, stacks);
     EffectResult::empty()
 }
 
@@ -687,8 +1366,7 @@ fn process_mode_shift_gain(state: &mut GameState, target: ActorId) -> EffectResu
             ModifierKind::ModeShift,
             stacks,
         );
-        state.monsters[i as usize].vitals.modifiers.is_new[ModifierKind::ModeShift as usize] =
-            false;
+        modifier_set_old(&mut state.monsters[i as usize].vitals.modifiers, ModifierKind::ModeShift);
     }
     EffectResult::empty()
 }
@@ -704,40 +1382,57 @@ fn process_modifier_set_not_new(state: &mut GameState) -> EffectResult {
 fn process_death(state: &mut GameState, actor: ActorId) -> EffectResult {
     match actor {
         ActorId::Character => EffectResult::top(vec![Effect::GameEnd]),
+        // Special case: the Awakened One's first death
+        ActorId::Monster(i) if modifier_has(&state.monsters[i as usize].vitals.modifiers, ModifierKind::Unawakened) => {
+            process_awaken(state, i)
+        }
         ActorId::Monster(i) => {
-            let idx = i as usize;
-            // Collect on-death effects before removing
-            let mut effects = Vec::new();
-
-            // Spore Cloud
-            if modifier_has(
-                &state.monsters[idx].vitals.modifiers,
-                ModifierKind::SporeCloud,
-            ) {
-                let stacks = modifier_stacks(
-                    &state.monsters[idx].vitals.modifiers,
-                    ModifierKind::SporeCloud,
-                );
-                effects.push(Effect::ModifierGain {
-                    target: ActorId::Character,
-                    kind: ModifierKind::Vulnerable,
-                    stacks,
-                });
-            }
+            // Collect on-death effects while the monster is still alive
+            let mut effects = run_hooks(state, actor, |h| h.on_death);
 
-            // Remove monster
-            state.monsters.remove(idx);
+            effects.extend(monster_remove(state, i));
+            EffectResult::top(effects)
+        }
+    }
+}
 
-            // Reindex: all monster indices above `idx` shift down by 1.
-            // Effects in the queue that reference monsters above `idx` would need updating.
-            // For simplicity, we handle this by ensuring we process death immediately.
+/// Reborn at full health without its debuffs and Curiosity, and straight
+/// into its second phase.
+fn process_awaken(state: &mut GameState, monster_idx: u8) -> EffectResult {
+    let vitals = &mut state.monsters[monster_idx as usize].vitals;
+    vitals.health = vitals.health_max;
+    let removed: Vec<ModifierKind> = modifier_debuffs(&vitals.modifiers).collect();
+    for kind in removed.into_iter().chain([ModifierKind::Curiosity, ModifierKind::Unawakened]) {
+        modifier_remove(&mut vitals.modifiers, kind);
+    }
+    EffectResult::top(vec![Effect::MonsterMoveUpdate { monster_idx }])
+}
 
-            if state.monsters.is_empty() {
-                effects.push(Effect::CombatEnd);
-            }
+/// Leaves combat without dying: no death hooks, so stolen gold is lost.
+fn process_escape(state: &mut GameState, actor: ActorId) -> EffectResult {
+    match actor {
+        ActorId::Character => EffectResult::empty(),
+        ActorId::Monster(i) => EffectResult::top(monster_remove(state, i)),
+    }
+}
 
-            EffectResult::top(effects)
-        }
+fn monster_remove(state: &mut GameState, monster_idx: u8) -> Vec<Effect> {
+    // The monster keeps its slot; its vitals stay visible but inert
+    let monster = &mut state.monsters[monster_idx as usize];
+    monster.alive = false;
+    monster.vitals.block = 0;
+    modifier_clear(&mut monster.vitals.modifiers);
+    if state.card_target == Some(monster_idx) {
+        state.card_target = None;
+    }
+
+    // Minions flee or die with their leader
+    let leader_alive = monster_ids_alive(state)
+        .any(|j| !modifier_has(&state.monsters[j as usize].vitals.modifiers, ModifierKind::Minion));
+    if leader_alive {
+        Vec::new()
+    } else {
+        vec![Effect::CombatEnd]
     }
 }
 
@@ -772,6 +1467,7 @@ fn process_combat_start(state: &mut GameState) -> EffectResult {
     state.exhaust_pile.clear();
     state.card_active = None;
     state.card_target = None;
+    state.combat_history.turns.clear();
 
     let mut effects: Vec<Effect> = Vec::new();
     for i in 0..state.monsters.len() {
@@ -782,11 +1478,18 @@ fn process_combat_start(state: &mut GameState) -> EffectResult {
     effects.push(Effect::TurnStart {
         actor: ActorId::Character,
     });
+    effects.extend(relic_combat_start_effects(state));
+
+    // Energy does not carry over between combats
+    state.energy.current = 0;
 
     EffectResult::top(effects)
 }
 
 fn process_combat_end(state: &mut GameState) -> EffectResult {
+    // Whatever is still queued belongs to the finished combat
+    state.effect_queue.clear();
+    state.monsters.clear();
     state.hand.clear();
     state.draw_pile.clear();
     state.discard_pile.clear();
@@ -796,140 +1499,114 @@ fn process_combat_end(state: &mut GameState) -> EffectResult {
     state.card_target = None;
     modifier_clear(&mut state.character.vitals.modifiers);
 
+    let mut effects = Vec::new();
+
     // Check room type
     let room = state.map.active_room_type().unwrap();
     match room {
-        RoomType::CombatBoss => EffectResult::top(vec![Effect::GameEnd]),
-        RoomType::CombatMonster => EffectResult::bot(vec![Effect::CardRewardRoll]),
-        RoomType::RestSite => unreachable!("combat end in rest site"),
+        RoomType::CombatBoss if state.act == ACT_FINAL => effects.push(Effect::GameEnd),
+        RoomType::CombatBoss if state.act == ACT_LAST => {
+            // A20: the second boss follows immediately
+            if let Some(boss) = state.map.boss_second.take() {
+                spawn_encounter(state, boss);
+                effects.push(Effect::CombatStart);
+                return EffectResult::bot(effects);
+            }
+            effects.push(boss_gold(state));
+
+            // Act 4 is only reachable with all three keys
+            if state.keys.all() {
+                effects.push(Effect::ActAdvance);
+            } else {
+                effects.push(Effect::GameEnd);
+            }
+        }
+        RoomType::CombatBoss => {
+            effects.push(boss_gold(state));
+            effects.push(Effect::BossChestOpen);
+        }
+        RoomType::CombatElite => {
+            if state.map.is_burning_elite() {
+                effects.push(Effect::KeyObtain {
+                    key: KeyKind::Emerald,
+                });
+            }
+            effects.push(Effect::CardRewardRoll);
+        }
+        RoomType::CombatMonster => effects.push(Effect::CardRewardRoll),
+        RoomType::RestSite | RoomType::Treasure => {
+            unreachable!("combat end in non-combat room")
+        }
     }
+    EffectResult::bot(effects)
 }
 
 fn process_turn_start(state: &mut GameState, actor: ActorId) -> EffectResult {
-    let mut effects = Vec::new();
-
-    // Block handling (blur preserves, otherwise reset)
-    let vitals = vitals_mut(state, actor);
-    let mut new_block: u16 = 0;
-    if modifier_has(&vitals.modifiers, ModifierKind::Blur) {
-        new_block += vitals.block;
-    }
-    if modifier_has(&vitals.modifiers, ModifierKind::NextTurnBlock) {
-        new_block += modifier_stacks(&vitals.modifiers, ModifierKind::NextTurnBlock) as u16;
-        modifier_remove(&mut vitals.modifiers, ModifierKind::NextTurnBlock);
-    }
-    effects.push(Effect::BlockSet {
+    // Block resets (Blur and Next Turn Block give some back through hooks;
+    // Blur reads the block before this resolves, so keep the hooks queued after it)
+    let mut effects = vec![Effect::BlockSet {
         target: actor,
-        amount: new_block,
-    });
-
-    // Phantasmal -> double damage
-    if modifier_has(
-        &vitals_ref(state, actor).modifiers,
-        ModifierKind::Phantasmal,
-    ) {
-        effects.push(Effect::ModifierGain {
-            target: actor,
-            kind: ModifierKind::DoubleDamage,
-            stacks: 1,
-        });
-    }
+        amount: 0,
+    }];
 
     // Character-specific
     if actor == ActorId::Character {
+        state.combat_history.turns.push(TurnRecord::default());
+        let reduction = modifier_stacks(&state.character.vitals.modifiers, ModifierKind::DrawReduction);
         effects.push(Effect::CardDraw {
-            count: CARDS_DRAWN_PER_TURN,
+            count: CARDS_DRAWN_PER_TURN.saturating_sub(reduction.max(0) as u8),
         });
-        let energy_gain = state.energy.max - state.energy.current;
+        let energy_gain = state.energy.max.saturating_sub(state.energy.current);
         effects.push(Effect::EnergyGain {
             amount: energy_gain,
         });
         effects.push(Effect::ModifierTick {
             target: ActorId::Character,
         });
-        for i in 0..state.monsters.len() {
+        for i in monster_ids_alive(state) {
             effects.push(Effect::ModifierTick {
-                target: ActorId::Monster(i as u8),
-            });
-        }
-
-        // Next turn energy
-        if modifier_has(
-            &state.character.vitals.modifiers,
-            ModifierKind::NextTurnEnergy,
-        ) {
-            let stacks = modifier_stacks(
-                &state.character.vitals.modifiers,
-                ModifierKind::NextTurnEnergy,
-            );
-            effects.push(Effect::EnergyGain {
-                amount: stacks as u8,
-            });
-            modifier_remove(
-                &mut state.character.vitals.modifiers,
-                ModifierKind::NextTurnEnergy,
-            );
-        }
-
-        // Infinite blades
-        if modifier_has(
-            &state.character.vitals.modifiers,
-            ModifierKind::InfiniteBlades,
-        ) {
-            let stacks = modifier_stacks(
-                &state.character.vitals.modifiers,
-                ModifierKind::InfiniteBlades,
-            );
-            effects.push(Effect::AddShivs {
-                count: stacks as u8,
+                target: ActorId::Monster(i),
             });
         }
     }
 
+    effects.extend(run_hooks(state, actor, |h| h.on_turn_start));
+
     EffectResult::top(effects)
 }
 
 fn process_turn_end(state: &mut GameState, actor: ActorId) -> EffectResult {
-    let mut effects = Vec::new();
-
-    // Ritual (common for both character and monsters)
-    {
-        let vitals = vitals_ref(state, actor);
-        if modifier_has(&vitals.modifiers, ModifierKind::Ritual)
-            && !vitals.modifiers.is_new[ModifierKind::Ritual as usize]
-        {
-            let stacks = modifier_stacks(&vitals.modifiers, ModifierKind::Ritual);
-            effects.push(Effect::ModifierGain {
-                target: actor,
-                kind: ModifierKind::Strength,
-                stacks,
-            });
-        }
-    }
+    // Modifier hooks (common for both character and monsters)
+    let mut effects = run_hooks(state, actor, |h| h.on_turn_end);
 
     // Monster: just return the common effects
     if let ActorId::Monster(_) = actor {
         return EffectResult::top(effects);
     }
 
+    // Status and curse cards that trigger while held at end of turn
+    for i in 0..state.hand.len() {
+        let card = state.combat_cards[state.hand[i]];
+        effects.extend(instantiate_templates(card.end_of_turn, ActorId::Character, Some(card.name), state));
+    }
+
+    // Ethereal cards exhaust if still in hand
+    for &card_idx in &state.hand {
+        if state.combat_cards[card_idx].ethereal {
+            effects.push(Effect::CardExhaust { card_idx });
+        }
+    }
+
     // Character turn end: discard hand, queue monster turns, start new character turn
     effects.push(Effect::CardDiscardAll);
     effects.push(Effect::ModifierSetNotNew);
 
-    for i in 0..state.monsters.len() {
-        let mi = ActorId::Monster(i as u8);
+    // Moves are instantiated when each monster acts, so one that dies first never does
+    for i in monster_ids_alive(state) {
+        let mi = ActorId::Monster(i);
         effects.push(Effect::TurnStart { actor: mi });
-
-        // Monster's move effects
-        let monster = &state.monsters[i];
-        if let Some(move_idx) = monster.move_current {
-            let move_effects = instantiate_templates(monster.moves[move_idx].effects, mi, state);
-            effects.extend(move_effects);
-        }
-
-        effects.push(Effect::MonsterMoveUpdate {
-            monster_idx: i as u8,
-        });
+        effects.push(Effect::MonsterMove { monster_idx: i });
+        effects.push(Effect::MonsterMoveUpdate { monster_idx: i });
         effects.push(Effect::TurnEnd { actor: mi });
     }
 
@@ -938,17 +1615,88 @@ fn process_turn_end(state: &mut GameState, actor: ActorId) -> EffectResult {
         actor: ActorId::Character,
     });
 
-    // Clear Burst at end of character turn
-    if modifier_has(&state.character.vitals.modifiers, ModifierKind::Burst) {
-        modifier_remove(&mut state.character.vitals.modifiers, ModifierKind::Burst);
+    EffectResult::top(effects)
+}
+
+fn process_monster_move(state: &mut GameState, monster_idx: u8) -> EffectResult {
+    let monster = &state.monsters[monster_idx as usize];
+    let Some(move_idx) = monster.move_current else {
+        return EffectResult::empty();
+    };
+    let templates = monster.moves[move_idx].effects;
+    let effects = instantiate_templates(templates, ActorId::Monster(monster_idx), None, state);
+    EffectResult::top(effects)
+}
+
+fn process_monster_spawn(
+    state: &mut GameState,
+    name: MonsterName,
+    health: Option<u16>,
+    minion: bool,
+) -> EffectResult {
+    if monster_ids_alive(state).count() >= MAX_MONSTERS_ALIVE {
+        return EffectResult::empty();
+    }
+
+    let mut monster = monster::spawn_monster(name, state.ascension, &mut state.rng);
+    if let Some(health) = health {
+        monster.vitals.health = health;
+        monster.vitals.health_max = health;
+    }
+    if minion {
+        make_minion(&mut monster);
     }
 
+    // Reuse a dead monster's slot unless effects still queued for it would land on this one
+    let free = (0..state.monsters.len()).find(|&i| {
+        let actor = ActorId::Monster(i as u8);
+        !state.monsters[i].alive && !state.effect_queue.iter().any(|e| involves_actor(e, |a| a == actor))
+    });
+    let monster_idx = match free {
+        Some(i) => {
+            state.monsters[i] = monster;
+            i as u8
+        }
+        None => {
+            state.monsters.push(monster);
+            (state.monsters.len() - 1) as u8
+        }
+    };
+    EffectResult::top(vec![Effect::MonsterMoveUpdate { monster_idx }])
+}
+
+fn process_monster_split(
+    state: &mut GameState,
+    monster_idx: u8,
+    into: &'static [MonsterName],
+) -> EffectResult {
+    // Replaced rather than killed: no death hooks, and the combat goes on
+    let monster = &mut state.monsters[monster_idx as usize];
+    let health = monster.vitals.health;
+    monster.alive = false;
+    monster.vitals.block = 0;
+    modifier_clear(&mut monster.vitals.modifiers);
+
+    let effects = into
+        .iter()
+        .map(|&name| Effect::MonsterSpawn {
+            name,
+            health: Some(health),
+            minion: false,
+        })
+        .collect();
     EffectResult::top(effects)
 }
 
+fn make_minion(monster: &mut Monster) {
+    modifier_apply(&mut monster.vitals.modifiers, ModifierKind::Minion, 1);
+    modifier_set_old(&mut monster.vitals.modifiers, ModifierKind::Minion);
+}
+
 fn process_monster_move_update(state: &mut GameState, monster_idx: u8) -> EffectResult {
     let i = monster_idx as usize;
-    let next = monster::select_next_move(&state.monsters[i], state.ascension, &mut state.rng);
+    let allies = monster_ids_alive(state).filter(|&j| j != monster_idx).count();
+    let next = monster::select_next_move(&state.monsters[i], state.ascension, allies, &mut state.rng);
     state.monsters[i].move_current = Some(next);
     state.monsters[i].move_history.push(next);
     EffectResult::empty()
@@ -958,71 +1706,168 @@ fn process_room_enter(state: &mut GameState) -> EffectResult {
     let room = state.map.active_room_type().unwrap();
     match room {
         RoomType::CombatBoss => {
-            // Spawn The Guardian
-            state.monsters = vec![monster::spawn_monster(
-                MonsterName::TheGuardian,
-                state.ascension,
-                &mut state.rng,
-            )];
+            spawn_encounter(state, state.map.boss);
+            EffectResult::top(vec![Effect::CombatStart])
+        }
+        RoomType::CombatElite => {
+            let table = encounters_elite(state.act);
+            let encounter = table[state.rng.random_range(0..table.len())];
+            spawn_encounter(state, encounter);
+            if state.map.is_burning_elite() {
+                buff_burning_elite(state);
+            }
             EffectResult::top(vec![Effect::CombatStart])
         }
         RoomType::CombatMonster => {
-            // Random encounter
-            let encounter: u8 = state.rng.random_range(0..3);
-            state.monsters = match encounter {
-                0 => vec![monster::spawn_monster(
-                    MonsterName::JawWorm,
-                    state.ascension,
-                    &mut state.rng,
-                )],
-                1 => vec![monster::spawn_monster(
-                    MonsterName::Cultist,
-                    state.ascension,
-                    &mut state.rng,
-                )],
-                2 => vec![
-                    monster::spawn_monster(
-                        MonsterName::FungiBeast,
-                        state.ascension,
-                        &mut state.rng,
-                    ),
-                    monster::spawn_monster(
-                        MonsterName::FungiBeast,
-                        state.ascension,
-                        &mut state.rng,
-                    ),
-                ],
-                _ => unreachable!(),
-            };
+            let names = roll_encounter_normal(state);
+            spawn_encounter(state, &names);
             EffectResult::top(vec![Effect::CombatStart])
         }
+        RoomType::Treasure => {
+            let roll: u32 = state.rng.random_range(0..100);
+            let size = if roll < CHANCE_CHEST_SMALL {
+                ChestSize::Small
+            } else if roll < CHANCE_CHEST_MEDIUM {
+                ChestSize::Medium
+            } else {
+                ChestSize::Large
+            };
+            EffectResult::top(vec![Effect::ChestOpen { size }])
+        }
         RoomType::RestSite => EffectResult::empty(),
     }
 }
 
+/// Weak pool for the first few fights of an act, then the strong pool. An
+/// encounter never repeats either of the previous two.
+fn roll_encounter_normal(state: &mut GameState) -> Vec<MonsterName> {
+    let weak_count = if state.act == ACT_FIRST { ENCOUNTERS_WEAK_ACT_1 } else { ENCOUNTERS_WEAK };
+    let table = encounters_normal(state.act, state.encounter_history.len() < weak_count);
+
+    let recent = &state.encounter_history[state.encounter_history.len().saturating_sub(2)..];
+    let mut options: Vec<&Encounter> = table.iter().copied().filter(|e| !recent.contains(&e.name)).collect();
+    if options.is_empty() {
+        options = table;
+    }
+
+    let total: u32 = options.iter().map(|e| e.weight as u32).sum();
+    let mut roll = state.rng.random_range(0..total);
+    let encounter = options
+        .into_iter()
+        .find(|e| {
+            let hit = roll < e.weight as u32;
+            roll = roll.saturating_sub(e.weight as u32);
+            hit
+        })
+        .unwrap();
+    state.encounter_history.push(encounter.name);
+
+    // (bag, index) of every monster already drawn from a bag
+    let mut drawn: Vec<(&[MonsterName], usize)> = Vec::new();
+    encounter
+        .slots
+        .iter()
+        .map(|slot| match *slot {
+            EncounterSlot::Fixed(name) => name,
+            EncounterSlot::OneOf(pool) => pool[state.rng.random_range(0..pool.len())],
+            EncounterSlot::FromBag(bag) => {
+                let left: Vec<usize> = (0..bag.len())
+                    .filter(|&i| !drawn.iter().any(|&(b, j)| std::ptr::eq(b, bag) && j == i))
+                    .collect();
+                let i = left[state.rng.random_range(0..left.len())];
+                drawn.push((bag, i));
+                bag[i]
+            }
+        })
+        .collect()
+}
+
+fn spawn_encounter(state: &mut GameState, names: &[MonsterName]) {
+    state.monsters = names
+        .iter()
+        .map(|&name| monster::spawn_monster(name, state.ascension, &mut state.rng))
+        .collect();
+
+    // The Gremlin Leader's starting gremlins flee once it dies
+    if names.contains(&MonsterName::GremlinLeader) {
+        for m in &mut state.monsters {
+            if m.name != MonsterName::GremlinLeader {
+                make_minion(m);
+            }
+        }
+    }
+}
+
+/// Burning elites get either extra Strength or 25% more max HP.
+fn buff_burning_elite(state: &mut GameState) {
+    let buff_strength = state.rng.random_bool(0.5);
+    let strength = state.act as i16 + 1;
+    for m in &mut state.monsters {
+        if buff_strength {
+            modifier_apply(&mut m.vitals.modifiers, ModifierKind::Strength, strength);
+            modifier_set_old(&mut m.vitals.modifiers, ModifierKind::Strength);
+        } else {
+            let health_max = (m.vitals.health_max as f32 * BURNING_ELITE_HEALTH_FACTOR) as u16;
+            m.vitals.health_max = health_max;
+            m.vitals.health = health_max;
+        }
+    }
+}
+
+fn process_act_advance(state: &mut GameState) -> EffectResult {
+    state.act += 1;
+    state.encounter_history.clear();
+    state.map = if state.act == ACT_FINAL {
+        generate_map_act_4()
+    } else {
+        generate_map(state.act, state.ascension, &mut state.rng)
+    };
+
+    // Full heal between acts; A5 only restores part of the missing health
+    let vitals = &state.character.vitals;
+    let heal = if state.ascension < 5 {
+        vitals.health_max
+    } else {
+        ((vitals.health_max - vitals.health) as f32 * ACT_HEAL_FACTOR_A5).round() as u16
+    };
+    EffectResult::top(vec![
+        Effect::HealthGain {
+            target: ActorId::Character,
+            amount: heal,
+        },
+        Effect::AwaitMapNode,
+    ])
+}
+
 // ---------------------------------------------------------------------------
 // Queue processing loop
 // ---------------------------------------------------------------------------
 
 pub fn process_queue(state: &mut GameState) {
-    while let Some(effect) = state.effect_queue.pop_front() {
-        // Clear queue before processing CombatEnd
-        if matches!(effect, Effect::CombatEnd) {
-            state.effect_queue.clear();
-        }
+    while process_queue_front(state) {}
+}
 
-        let result = process_effect(state, effect);
+/// Processes the effect at the front of the queue. Returns false once the
+/// queue is empty or waiting for input.
+pub fn process_queue_front(state: &mut GameState) -> bool {
+    let Some(effect) = state.effect_queue.pop_front() else {
+        return false;
+    };
 
-        // Push new effects
-        for e in result.push_top.into_iter().rev() {
-            state.effect_queue.push_front(e);
-        }
-        for e in result.push_bot {
-            state.effect_queue.push_back(e);
-        }
+    // Clear queue before processing CombatEnd
+    if matches!(effect, Effect::CombatEnd) {
+        state.effect_queue.clear();
+    }
 
-        if result.halt || result.pause {
-            return;
-        }
+    let result = process_effect(state, effect);
+
+    // Push new effects
+    for e in result.push_top.into_iter().rev() {
+        state.effect_queue.push_front(e);
+    }
+    for e in result.push_bot {
+        state.effect_queue.push_back(e);
     }
+
+    !(result.halt || result.pause)
 }
@@ -0,0 +1,231 @@
+// Content registry: the IDs of cards, monsters and modifiers added at runtime
+// and every definition loaded from Python. Built-in IDs are the associated
+// consts on `CardName`, `MonsterName` and `ModifierKind`; registered content
+// takes the IDs after them. Names and IDs are never released, so an ID stays
+// valid for the rest of the process even after its definition is cleared.
+// Loaded data is interned the same way: it lives for the rest of the process,
+// but loading an identical definition again reuses it instead of leaking a copy.
+
+use std::any::Any;
+use std::sync::{Mutex, RwLock};
+
+use crate::cards::Card;
+use crate::encounter::{Encounter, EncounterPool};
+use crate::modifier::{MODIFIER_BUILTIN, Modifier, ModifierKind};
+use crate::monster::MonsterDef;
+use crate::types::{CARD_NAMES_BUILTIN, CardName, MONSTER_NAMES_BUILTIN, MonsterName};
+
+struct Registry {
+    card_names: Vec<&'static str>,
+    monster_names: Vec<&'static str>,
+    modifiers: Vec<(&'static str, &'static Modifier)>,
+    cards: Vec<Card>,
+    monsters: Vec<&'static MonsterDef>,
+    encounters: Vec<(u8, EncounterPool, &'static Encounter)>,
+    groups: Vec<(u8, EncounterPool, &'static [MonsterName])>,
+}
+
+static REGISTRY: RwLock<Registry> = RwLock::new(Registry {
+    card_names: Vec::new(),
+    monster_names: Vec::new(),
+    modifiers: Vec::new(),
+    cards: Vec::new(),
+    monsters: Vec::new(),
+    encounters: Vec::new(),
+    groups: Vec::new(),
+});
+
+static INTERNED: Mutex<Vec<&'static (dyn Any + Send + Sync)>> = Mutex::new(Vec::new());
+
+/// A `'static` copy of `value`, shared with every equal value interned before.
+pub fn intern<T: PartialEq + Send + Sync + 'static>(value: T) -> &'static T {
+    let mut pool = INTERNED.lock().unwrap();
+    if let Some(v) = pool.iter().find_map(|v| v.downcast_ref::<T>().filter(|v| **v == value)) {
+        return v;
+    }
+    let v: &'static T = Box::leak(Box::new(value));
+    pool.push(v);
+    v
+}
+
+fn leak(name: &str) -> &'static str {
+    intern(name.to_owned())
+}
+
+// ---------------------------------------------------------------------------
+// Cards
+// ---------------------------------------------------------------------------
+
+pub fn card_find(name: &str) -> Option<CardName> {
+    let reg = REGISTRY.read().unwrap();
+    let idx = reg.card_names.iter().position(|n| *n == name)?;
+    Some(CardName((CARD_NAMES_BUILTIN + idx) as u16))
+}
+
+pub fn card_name(name: CardName) -> &'static str {
+    REGISTRY.read().unwrap().card_names[name.0 as usize - CARD_NAMES_BUILTIN]
+}
+
+/// Returns the existing ID when the name is already taken.
+pub fn card_register(name: &str) -> CardName {
+    if let Some(id) = CardName::from_name(name) {
+        return id;
+    }
+    let mut reg = REGISTRY.write().unwrap();
+    reg.card_names.push(leak(name));
+    CardName((CARD_NAMES_BUILTIN + reg.card_names.len() - 1) as u16)
+}
+
+pub fn card_loaded(name: CardName, upgraded: bool) -> Option<Card> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .cards
+        .iter()
+        .find(|c| c.name == name && c.upgraded == upgraded)
+        .copied()
+}
+
+/// Registered cards with a loaded definition, in registration order.
+pub fn cards_registered() -> Vec<Card> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .cards
+        .iter()
+        .filter(|c| !c.name.is_builtin() && !c.upgraded)
+        .copied()
+        .collect()
+}
+
+pub fn cards_insert(cards: Vec<Card>) {
+    let mut reg = REGISTRY.write().unwrap();
+    for card in cards {
+        reg.cards.retain(|c| !(c.name == card.name && c.upgraded == card.upgraded));
+        reg.cards.push(card);
+    }
+}
+
+pub fn cards_clear() {
+    REGISTRY.write().unwrap().cards.clear();
+}
+
+// ---------------------------------------------------------------------------
+// Monsters
+// ---------------------------------------------------------------------------
+
+pub fn monster_find(name: &str) -> Option<MonsterName> {
+    let reg = REGISTRY.read().unwrap();
+    let idx = reg.monster_names.iter().position(|n| *n == name)?;
+    Some(MonsterName((MONSTER_NAMES_BUILTIN + idx) as u16))
+}
+
+pub fn monster_name(name: MonsterName) -> &'static str {
+    REGISTRY.read().unwrap().monster_names[name.0 as usize - MONSTER_NAMES_BUILTIN]
+}
+
+/// Returns the existing ID when the name is already taken.
+pub fn monster_register(name: &str) -> MonsterName {
+    if let Some(id) = MonsterName::from_name(name) {
+        return id;
+    }
+    let mut reg = REGISTRY.write().unwrap();
+    reg.monster_names.push(leak(name));
+    MonsterName((MONSTER_NAMES_BUILTIN + reg.monster_names.len() - 1) as u16)
+}
+
+pub fn monster_loaded(name: MonsterName) -> Option<&'static MonsterDef> {
+    REGISTRY.read().unwrap().monsters.iter().find(|m| m.name == name).copied()
+}
+
+pub fn monsters_insert(monsters: Vec<MonsterDef>) {
+    let mut reg = REGISTRY.write().unwrap();
+    for monster in monsters {
+        reg.monsters.retain(|m| m.name != monster.name);
+        reg.monsters.push(intern(monster));
+    }
+}
+
+pub fn monsters_clear() {
+    REGISTRY.write().unwrap().monsters.clear();
+}
+
+// ---------------------------------------------------------------------------
+// Modifiers
+// ---------------------------------------------------------------------------
+
+pub fn modifier_find(name: &str) -> Option<ModifierKind> {
+    let reg = REGISTRY.read().unwrap();
+    let idx = reg.modifiers.iter().position(|(n, _)| *n == name)?;
+    Some(ModifierKind((MODIFIER_BUILTIN + idx) as u16))
+}
+
+pub fn modifier_name(kind: ModifierKind) -> &'static str {
+    REGISTRY.read().unwrap().modifiers[kind.0 as usize - MODIFIER_BUILTIN].0
+}
+
+pub fn modifier_def(kind: ModifierKind) -> &'static Modifier {
+    REGISTRY.read().unwrap().modifiers[kind.0 as usize - MODIFIER_BUILTIN].1
+}
+
+/// Registers a new modifier, or replaces the definition of a registered one.
+/// `def` receives the ID to store in `Modifier::kind`. Built-ins can't be
+/// replaced since their hooks and damage rules live in code.
+pub fn modifier_register(name: &str, def: impl FnOnce(ModifierKind) -> Modifier) -> Result<ModifierKind, String> {
+    if let Some(kind) = ModifierKind::from_name(name) {
+        if kind.is_builtin() {
+            return Err(format!("built-in modifier: {name}"));
+        }
+        let mut reg = REGISTRY.write().unwrap();
+        reg.modifiers[kind.0 as usize - MODIFIER_BUILTIN].1 = intern(def(kind));
+        return Ok(kind);
+    }
+
+    let mut reg = REGISTRY.write().unwrap();
+    let idx = MODIFIER_BUILTIN + reg.modifiers.len();
+    let kind = ModifierKind(u16::try_from(idx).map_err(|_| format!("no room for modifier {name}"))?);
+    reg.modifiers.push((leak(name), intern(def(kind))));
+    Ok(kind)
+}
+
+// ---------------------------------------------------------------------------
+// Encounters
+// ---------------------------------------------------------------------------
+
+/// Registered normal encounters for an act's pool, in registration order.
+pub fn encounters(act: u8, pool: EncounterPool) -> Vec<&'static Encounter> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .encounters
+        .iter()
+        .filter(|(a, p, _)| *a == act && *p == pool)
+        .map(|(_, _, e)| *e)
+        .collect()
+}
+
+/// Registered elite or boss fights for an act, in registration order.
+pub fn groups(act: u8, pool: EncounterPool) -> Vec<&'static [MonsterName]> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .groups
+        .iter()
+        .filter(|(a, p, _)| *a == act && *p == pool)
+        .map(|(_, _, g)| *g)
+        .collect()
+}
+
+pub fn encounters_insert(encounters: Vec<(u8, EncounterPool, Encounter)>, groups: Vec<(u8, EncounterPool, &'static [MonsterName])>) {
+    let mut reg = REGISTRY.write().unwrap();
+    for (act, pool, encounter) in encounters {
+        reg.encounters.push((act, pool, intern(encounter)));
+    }
+    reg.groups.extend(groups);
+}
+
+pub fn encounters_clear() {
+    let mut reg = REGISTRY.write().unwrap();
+    reg.encounters.clear();
+    reg.groups.clear();
+}
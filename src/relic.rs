@@ -0,0 +1,111 @@
+// Relic definitions: rarity pools and combat-start triggers.
+
+use crate::effect::Effect;
+use crate::modifier::ModifierKind;
+use crate::state::{GameState, monster_ids_alive};
+use crate::types::*;
+
+// ---------------------------------------------------------------------------
+// Pools (static data)
+// ---------------------------------------------------------------------------
+
+pub const RELIC_POOL_COMMON: &[RelicName] = &[RelicName::Anchor, RelicName::BagOfMarbles, RelicName::Vajra];
+
+pub const RELIC_POOL_UNCOMMON: &[RelicName] = &[RelicName::Pear];
+
+// Chemical X is a shop relic in the game; with no shops it is offered as a rare
+pub const RELIC_POOL_RARE: &[RelicName] = &[RelicName::ChemicalX, RelicName::Mango];
+
+pub const RELIC_POOL_BOSS: &[RelicName] = &[
+    RelicName::BustedCrown,
+    RelicName::Ectoplasm,
+    RelicName::PhilosophersStone,
+];
+
+pub fn relic_pool(rarity: RelicRarity) -> &'static [RelicName] {
+    match rarity {
+        RelicRarity::Common => RELIC_POOL_COMMON,
+        RelicRarity::Uncommon => RELIC_POOL_UNCOMMON,
+        RelicRarity::Rare => RELIC_POOL_RARE,
+        RelicRarity::Boss => RELIC_POOL_BOSS,
+    }
+}
+
+pub fn relic_rarity(name: RelicName) -> RelicRarity {
+    match name {
+        RelicName::Anchor | RelicName::BagOfMarbles | RelicName::Vajra => RelicRarity::Common,
+        RelicName::Pear => RelicRarity::Uncommon,
+        RelicName::ChemicalX | RelicName::Mango => RelicRarity::Rare,
+        RelicName::BustedCrown | RelicName::Ectoplasm | RelicName::PhilosophersStone => {
+            RelicRarity::Boss
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Queries
+// ---------------------------------------------------------------------------
+
+pub fn relic_has(state: &GameState, name: RelicName) -> bool {
+    state.relics.contains(&name)
+}
+
+/// Boss relics that trade a drawback for one extra energy per turn.
+pub fn relic_grants_energy(name: RelicName) -> bool {
+    matches!(
+        name,
+        RelicName::BustedCrown | RelicName::Ectoplasm | RelicName::PhilosophersStone
+    )
+}
+
+/// Max HP raised (and healed) when the relic is picked up.
+pub fn relic_health_max_gain(name: RelicName) -> u16 {
+    match name {
+        RelicName::Pear => 10,
+        RelicName::Mango => 14,
+        _ => 0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Triggers
+// ---------------------------------------------------------------------------
+
+/// Effects queued after the character's first turn start of every combat.
+pub fn relic_combat_start_effects(state: &GameState) -> Vec<Effect> {
+    let mut effects = Vec::new();
+    for &name in &state.relics {
+        match name {
+            RelicName::Anchor => effects.push(Effect::BlockGain {
+                target: ActorId::Character,
+                amount: 10,
+                from_card: false,
+            }),
+            RelicName::BagOfMarbles => {
+                for i in monster_ids_alive(state) {
+                    effects.push(Effect::ModifierGain {
+                        target: ActorId::Monster(i),
+                        kind: ModifierKind::Vulnerable,
+                        stacks: 1,
+                    });
+                }
+            }
+            RelicName::PhilosophersStone => {
+                for i in monster_ids_alive(state) {
+                    effects.push(Effect::ModifierGain {
+                        target: ActorId::Monster(i),
+                        kind: ModifierKind::Strength,
+                        stacks: 1,
+                    });
+                }
+            }
+            RelicName::Vajra => effects.push(Effect::ModifierGain {
+                target: ActorId::Character,
+                kind: ModifierKind::Strength,
+                stacks: 1,
+            }),
+            _ => {}
+        }
+    }
+    effects
+}
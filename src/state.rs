@@ -45,74 +45,123 @@ pub struct Character {
 // Intent (for monsters)
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, Default)]
+/// The game's intent icons. Attack kinds come with damage in `Intent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntentKind {
+    Attack,
+    AttackBuff,
+    AttackDebuff,
+    AttackDefend,
+    Buff,
+    Debuff,
+    StrongDebuff,
+    Defend,
+    DefendBuff,
+    DefendDebuff,
+    Escape,
+    Magic,
+    Sleep,
+    Stun,
+    #[default]
+    Unknown,
+}
+
+impl IntentKind {
+    pub fn block(self) -> bool {
+        matches!(self, Self::AttackDefend | Self::Defend | Self::DefendBuff | Self::DefendDebuff)
+    }
+
+    pub fn buff(self) -> bool {
+        matches!(self, Self::AttackBuff | Self::Buff | Self::DefendBuff)
+    }
+
+    pub fn debuff(self) -> bool {
+        matches!(self, Self::AttackDebuff | Self::Debuff | Self::StrongDebuff | Self::DefendDebuff)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Intent {
     pub damage: Option<u16>,
     pub instances: Option<u8>,
-    pub block: bool,
-    pub buff: bool,
-    pub debuff: bool,
+    pub kind: IntentKind,
 }
 
 impl Intent {
-    pub const fn attack(damage: u16, instances: u8) -> Self {
-        Intent {
-            damage: Some(damage),
-            instances: Some(instances),
-            block: false,
-            buff: false,
-            debuff: false,
-        }
-    }
-
-    pub const fn attack_block(damage: u16, instances: u8) -> Self {
+    const fn with_attack(kind: IntentKind, damage: u16, instances: u8) -> Self {
         Intent {
             damage: Some(damage),
             instances: Some(instances),
-            block: true,
-            buff: false,
-            debuff: false,
+            kind,
         }
     }
 
-    pub const fn buff() -> Self {
+    const fn without_attack(kind: IntentKind) -> Self {
         Intent {
             damage: None,
             instances: None,
-            block: false,
-            buff: true,
-            debuff: false,
+            kind,
         }
     }
 
-    pub const fn buff_block() -> Self {
-        Intent {
-            damage: None,
-            instances: None,
-            block: true,
-            buff: true,
-            debuff: false,
-        }
+    pub const fn attack(damage: u16, instances: u8) -> Self {
+        Self::with_attack(IntentKind::Attack, damage, instances)
     }
 
-    pub const fn block_only() -> Self {
-        Intent {
-            damage: None,
-            instances: None,
-            block: true,
-            buff: false,
-            debuff: false,
-        }
+    pub const fn attack_buff(damage: u16, instances: u8) -> Self {
+        Self::with_attack(IntentKind::AttackBuff, damage, instances)
+    }
+
+    pub const fn attack_debuff(damage: u16, instances: u8) -> Self {
+        Self::with_attack(IntentKind::AttackDebuff, damage, instances)
+    }
+
+    pub const fn attack_defend(damage: u16, instances: u8) -> Self {
+        Self::with_attack(IntentKind::AttackDefend, damage, instances)
+    }
+
+    pub const fn buff() -> Self {
+        Self::without_attack(IntentKind::Buff)
     }
 
     pub const fn debuff() -> Self {
-        Intent {
-            damage: None,
-            instances: None,
-            block: false,
-            buff: false,
-            debuff: true,
-        }
+        Self::without_attack(IntentKind::Debuff)
+    }
+
+    pub const fn strong_debuff() -> Self {
+        Self::without_attack(IntentKind::StrongDebuff)
+    }
+
+    pub const fn defend() -> Self {
+        Self::without_attack(IntentKind::Defend)
+    }
+
+    pub const fn defend_buff() -> Self {
+        Self::without_attack(IntentKind::DefendBuff)
+    }
+
+    pub const fn defend_debuff() -> Self {
+        Self::without_attack(IntentKind::DefendDebuff)
+    }
+
+    pub const fn escape() -> Self {
+        Self::without_attack(IntentKind::Escape)
+    }
+
+    pub const fn magic() -> Self {
+        Self::without_attack(IntentKind::Magic)
+    }
+
+    pub const fn sleep() -> Self {
+        Self::without_attack(IntentKind::Sleep)
+    }
+
+    pub const fn stun() -> Self {
+        Self::without_attack(IntentKind::Stun)
+    }
+
+    pub const fn unknown() -> Self {
+        Self::without_attack(IntentKind::Unknown)
     }
 }
 
@@ -139,6 +188,8 @@ pub struct Monster {
     pub moves: Vec<Move>,
     pub move_current: Option<usize>,
     pub move_history: Vec<usize>,
+    // Dead monsters keep their slot so ActorId::Monster(i) stays valid
+    pub alive: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -151,6 +202,82 @@ pub struct Energy {
     pub max: u8,
 }
 
+// ---------------------------------------------------------------------------
+// Combat history
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CardsPlayed {
+    pub attack: u16,
+    pub skill: u16,
+    pub power: u16,
+    pub other: u16, // statuses and curses
+}
+
+impl CardsPlayed {
+    pub fn add(&mut self, kind: CardKind) {
+        match kind {
+            CardKind::Attack => self.attack += 1,
+            CardKind::Skill => self.skill += 1,
+            CardKind::Power => self.power += 1,
+            CardKind::Status | CardKind::Curse => self.other += 1,
+        }
+    }
+
+    pub fn total(&self) -> u16 {
+        self.attack + self.skill + self.power + self.other
+    }
+}
+
+/// One character turn, including the monster turns that follow it.
+/// Damage is health actually lost, after block.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TurnRecord {
+    pub cards_played: CardsPlayed,
+    pub cards_discarded: u16,
+    pub damage_dealt: u16,
+    pub damage_taken: u16,
+}
+
+impl TurnRecord {
+    fn merge(mut self, other: &TurnRecord) -> TurnRecord {
+        self.cards_played.attack += other.cards_played.attack;
+        self.cards_played.skill += other.cards_played.skill;
+        self.cards_played.power += other.cards_played.power;
+        self.cards_played.other += other.cards_played.other;
+        self.cards_discarded += other.cards_discarded;
+        self.damage_dealt += other.damage_dealt;
+        self.damage_taken += other.damage_taken;
+        self
+    }
+}
+
+/// Turns of the current combat, or of the last one until the next starts.
+/// The last record is this turn.
+#[derive(Debug, Clone, Default)]
+pub struct CombatHistory {
+    pub turns: Vec<TurnRecord>,
+}
+
+impl CombatHistory {
+    /// 1 on the first turn, 0 before the first combat.
+    pub fn turn(&self) -> u16 {
+        self.turns.len() as u16
+    }
+
+    pub fn this_turn(&self) -> TurnRecord {
+        self.turns.last().copied().unwrap_or_default()
+    }
+
+    pub fn this_turn_mut(&mut self) -> Option<&mut TurnRecord> {
+        self.turns.last_mut()
+    }
+
+    pub fn combat(&self) -> TurnRecord {
+        self.turns.iter().fold(TurnRecord::default(), TurnRecord::merge)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Map
 // ---------------------------------------------------------------------------
@@ -161,6 +288,7 @@ pub struct MapNode {
     pub x: usize,
     pub room_type: RoomType,
     pub x_next: Vec<usize>,
+    pub burning: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -169,6 +297,8 @@ pub struct Map {
     pub active_y: Option<usize>,
     pub active_x: Option<usize>,
     pub boss_room_y: usize,
+    pub boss: &'static [MonsterName],
+    pub boss_second: Option<&'static [MonsterName]>,
 }
 
 impl Map {
@@ -192,6 +322,31 @@ impl Map {
     pub fn is_boss_room(&self) -> bool {
         self.active_y == Some(self.boss_room_y)
     }
+
+    pub fn is_last_floor(&self) -> bool {
+        self.active_y == Some(self.nodes.len() - 1)
+    }
+
+    pub fn is_burning_elite(&self) -> bool {
+        self.active_node().is_some_and(|n| n.burning)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Keys (unlock Act 4)
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Keys {
+    pub ruby: bool,
+    pub emerald: bool,
+    pub sapphire: bool,
+}
+
+impl Keys {
+    pub fn all(&self) -> bool {
+        self.ruby && self.emerald && self.sapphire
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -202,6 +357,7 @@ impl Map {
 pub struct GameState {
     // Meta
     pub ascension: u8,
+    pub act: u8,
     pub fsm: Fsm,
     pub rng: SmallRng,
 
@@ -209,8 +365,11 @@ pub struct GameState {
     pub character: Character,
     pub energy: Energy,
 
-    // Permanent deck
+    // Permanent deck, relics and gold
     pub deck: Vec<Card>,
+    pub relics: Vec<RelicName>,
+    pub gold: u16,
+    pub keys: Keys,
 
     // Combat card pool + piles (indices into combat_cards)
     pub combat_cards: Vec<Card>,
@@ -222,12 +381,16 @@ pub struct GameState {
     // Active card / target
     pub card_active: Option<usize>,
     pub card_target: Option<u8>,
+    pub card_x: Option<u16>, // X of the X-cost card being played
+    pub combat_history: CombatHistory,
 
     // Monsters
     pub monsters: Vec<Monster>,
+    pub encounter_history: Vec<&'static str>, // normal encounters this act
 
-    // Card rewards
+    // Card and relic rewards
     pub card_rewards: Vec<Card>,
+    pub relic_rewards: Vec<RelicName>,
 
     // Map
     pub map: Map,
@@ -235,3 +398,33 @@ pub struct GameState {
     // Effect queue
     pub effect_queue: VecDeque<Effect>,
 }
+
+pub fn vitals_mut(state: &mut GameState, actor: ActorId) -> &mut Vitals {
+    match actor {
+        ActorId::Character => &mut state.character.vitals,
+        ActorId::Monster(i) => &mut state.monsters[i as usize].vitals,
+    }
+}
+
+pub fn vitals_ref(state: &GameState, actor: ActorId) -> &Vitals {
+    match actor {
+        ActorId::Character => &state.character.vitals,
+        ActorId::Monster(i) => &state.monsters[i as usize].vitals,
+    }
+}
+
+pub fn actor_alive(state: &GameState, actor: ActorId) -> bool {
+    match actor {
+        ActorId::Character => true,
+        ActorId::Monster(i) => state.monsters[i as usize].alive,
+    }
+}
+
+pub fn monster_ids_alive(state: &GameState) -> impl Iterator<Item = u8> + '_ {
+    state
+        .monsters
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.alive)
+        .map(|(i, _)| i as u8)
+}
@@ -0,0 +1,476 @@
+// Card and move text generated from effect templates, e.g. "Deal 3 damage.
+// Apply 1 Weak." Values are live (Strength, Dexterity, Weak, ...) when a game
+// state and the acting actor are given, and upgrade texts mark each changed
+// number with its delta.
+
+use std::mem::discriminant;
+
+use crate::cards::{Card, get_card};
+use crate::effect::{Condition, CountKind, EffectTemplate, PileKind, SelectionKind, TargetKind};
+use crate::modifier::ModifierKind;
+use crate::process::{block_card_amount, count_value, damage_attack_outgoing};
+use crate::state::{GameState, Move};
+use crate::types::{ActorId, CardName};
+
+/// Who a template affects, seen from the actor using it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Who {
+    Owner,
+    Enemy,
+    AllEnemies,
+    RandomEnemy,
+    AllAllies,
+    RandomAlly,
+}
+
+fn who(target: TargetKind, owner: ActorId) -> Who {
+    match (owner, target) {
+        (_, TargetKind::Source) => Who::Owner,
+        (ActorId::Character, TargetKind::Character) => Who::Owner,
+        (ActorId::Character, TargetKind::CardTarget) => Who::Enemy,
+        (ActorId::Character, TargetKind::AllMonsters) => Who::AllEnemies,
+        (ActorId::Character, TargetKind::RandomMonster) => Who::RandomEnemy,
+        (ActorId::Character, TargetKind::AllAllies | TargetKind::RandomAlly) => Who::Owner,
+        (ActorId::Monster(_), TargetKind::Character | TargetKind::CardTarget) => Who::Enemy,
+        (ActorId::Monster(_), TargetKind::AllMonsters | TargetKind::AllAllies) => Who::AllAllies,
+        (ActorId::Monster(_), TargetKind::RandomMonster | TargetKind::RandomAlly) => Who::RandomAlly,
+    }
+}
+
+/// Live values come from `state` for `owner`; without a state the printed
+/// base values are used.
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    state: Option<&'a GameState>,
+    owner: ActorId,
+    card: Option<CardName>,
+}
+
+// ---------------------------------------------------------------------------
+// Entry points
+// ---------------------------------------------------------------------------
+
+/// Text of a card, with live values when the character's `state` is given.
+pub fn card_text(card: &Card, state: Option<&GameState>) -> String {
+    let ctx = Context {
+        state,
+        owner: ActorId::Character,
+        card: Some(card.name),
+    };
+    card_text_with(card, None, ctx)
+}
+
+/// Text of the upgraded card with each changed value followed by its delta,
+/// e.g. "Deal 11 (+3) damage." None when the card is upgraded or doesn't change.
+pub fn card_text_upgrade(card: &Card) -> Option<String> {
+    if card.upgraded {
+        return None;
+    }
+    let plus = get_card(card.name, true);
+    let ctx = Context {
+        state: None,
+        owner: ActorId::Character,
+        card: Some(card.name),
+    };
+    let text = card_text_with(&plus, Some(card), ctx);
+    (text != card_text_with(card, None, ctx) || plus.cost != card.cost).then_some(text)
+}
+
+/// Text of a monster's move with the monster's live values.
+pub fn move_text(mv: &Move, state: &GameState, monster_idx: u8) -> String {
+    let ctx = Context {
+        state: Some(state),
+        owner: ActorId::Monster(monster_idx),
+        card: None,
+    };
+    effects_text(mv.effects, None, ctx).join(" ")
+}
+
+fn card_text_with(card: &Card, prev: Option<&Card>, ctx: Context) -> String {
+    let mut parts = vec![];
+    if let Some(prev) = prev.filter(|p| p.cost != card.cost) {
+        parts.push(format!("Costs {}.", number(card.cost as i32, Some(prev.cost as i32))));
+    }
+    if card.unplayable {
+        parts.push("Unplayable.".to_string());
+    }
+    if card.innate {
+        parts.push("Innate.".to_string());
+    }
+    if card.ethereal {
+        parts.push("Ethereal.".to_string());
+    }
+    parts.extend(effects_text(card.effects, prev.map(|p| p.effects), ctx));
+    if !card.end_of_turn.is_empty() {
+        let effects = effects_text(card.end_of_turn, prev.map(|p| p.end_of_turn), ctx);
+        parts.push(format!("At the end of your turn, {}.", clause(effects)));
+    }
+    if !card.on_draw.is_empty() {
+        let effects = effects_text(card.on_draw, prev.map(|p| p.on_draw), ctx);
+        parts.push(format!("When drawn, {}.", clause(effects)));
+    }
+    if card.exhaust {
+        parts.push("Exhaust.".to_string());
+    }
+    parts.join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// Effects
+// ---------------------------------------------------------------------------
+
+/// One sentence per run of identical templates, so repeated hits read "2 times".
+fn effects_text(effects: &[EffectTemplate], prev: Option<&[EffectTemplate]>, ctx: Context) -> Vec<String> {
+    let prev_runs = prev.map(runs).unwrap_or_default();
+    runs(effects)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (tmpl, times))| {
+            // Upgrades keep the template order, so runs line up by position
+            let prev = prev_runs
+                .get(i)
+                .filter(|(p, _)| discriminant(*p) == discriminant(tmpl))
+                .copied();
+            effect_text(tmpl, times, prev, ctx)
+        })
+        .collect()
+}
+
+fn runs(effects: &[EffectTemplate]) -> Vec<(&EffectTemplate, u8)> {
+    let mut runs: Vec<(&EffectTemplate, u8)> = vec![];
+    for tmpl in effects {
+        match runs.last_mut() {
+            Some((last, times)) if *last == tmpl => *times += 1,
+            _ => runs.push((tmpl, 1)),
+        }
+    }
+    runs
+}
+
+fn effect_text(tmpl: &EffectTemplate, times: u8, prev: Option<(&EffectTemplate, u8)>, ctx: Context) -> String {
+    // The previous value of the same field, for upgrade deltas
+    let before = |f: fn(&EffectTemplate) -> Option<i32>| prev.and_then(|(p, _)| f(p));
+    let repeat = match times {
+        1 => String::new(),
+        n => format!(" {} times", number(n as i32, prev.map(|(_, t)| t as i32))),
+    };
+
+    match *tmpl {
+        EffectTemplate::DamagePhysical { base, target } => {
+            let before = before(|p| match p {
+                EffectTemplate::DamagePhysical { base, .. } => Some(*base as i32),
+                _ => None,
+            });
+            let value = live_damage(base, ctx);
+            format!("Deal {} damage{}{repeat}.", number(value, before), to(who(target, ctx.owner)))
+        }
+        EffectTemplate::DamagePhysicalPerHealth { divisor, target } => {
+            let who = who(target, ctx.owner);
+            match (ctx.state, who) {
+                (Some(state), Who::Enemy) if matches!(ctx.owner, ActorId::Monster(_)) => {
+                    let base = state.character.vitals.health / divisor + 1;
+                    format!("Deal {} damage{repeat}.", live_damage(base, ctx))
+                }
+                _ => format!("Deal damage equal to 1/{divisor} of current HP + 1{}{repeat}.", to(who)),
+            }
+        }
+        EffectTemplate::DamageDeal { amount, target } => {
+            let before = before(|p| match p {
+                EffectTemplate::DamageDeal { amount, .. } => Some(*amount as i32),
+                _ => None,
+            });
+            let value = number(amount as i32, before);
+            match who(target, ctx.owner) {
+                Who::Owner => format!("Take {value} damage{repeat}."),
+                who => format!("Deal {value} damage{}{repeat}.", to(who)),
+            }
+        }
+        EffectTemplate::HealthLoss { amount, target } => {
+            let before = before(|p| match p {
+                EffectTemplate::HealthLoss { amount, .. } => Some(*amount as i32),
+                _ => None,
+            });
+            let value = number(amount as i32, before);
+            match who(target, ctx.owner) {
+                Who::Owner => format!("Lose {value} HP{repeat}."),
+                who => format!("{} {value} HP{repeat}.", subject_loses(who)),
+            }
+        }
+        EffectTemplate::BlockGain { amount, target } => {
+            let before = before(|p| match p {
+                EffectTemplate::BlockGain { amount, .. } => Some(*amount as i32),
+                _ => None,
+            });
+            let who = who(target, ctx.owner);
+            let value = match (ctx.state, who, ctx.card) {
+                (Some(state), Who::Owner, Some(_)) => block_card_amount(state, ctx.owner, amount),
+                _ => amount,
+            };
+            let value = number(value as i32, before);
+            match who {
+                Who::Owner => format!("Gain {value} Block{repeat}."),
+                _ => format!("Give {} {value} Block{repeat}.", object(who)),
+            }
+        }
+        EffectTemplate::ModifierGain { kind, stacks, target } => {
+            let before = before(|p| match p {
+                EffectTemplate::ModifierGain { stacks, .. } => Some(stacks.abs() as i32),
+                _ => None,
+            });
+            let who = who(target, ctx.owner);
+            let value = number(stacks.abs() as i32, before);
+            let kind = modifier_label(kind);
+            match (who, stacks < 0) {
+                (Who::Owner, false) => format!("Gain {value} {kind}{repeat}."),
+                (Who::Owner, true) => format!("Lose {value} {kind}{repeat}."),
+                (Who::AllAllies | Who::RandomAlly, false) => format!("Give {} {value} {kind}{repeat}.", object(who)),
+                (_, false) => format!("Apply {value} {kind}{}{repeat}.", to(who)),
+                (_, true) => format!("{} {value} {kind}{repeat}.", subject_loses(who)),
+            }
+        }
+        EffectTemplate::ModifierRemove { kind, target } => match who(target, ctx.owner) {
+            Who::Owner => format!("Remove your {}.", modifier_label(kind)),
+            who => format!("Remove {} from {}.", modifier_label(kind), object(who)),
+        },
+        EffectTemplate::ModifierMultiply { kind, factor, target } => {
+            let verb = match factor {
+                2 => "Double".to_string(),
+                3 => "Triple".to_string(),
+                n => format!("Multiply by {n}"),
+            };
+            let whose = match who(target, ctx.owner) {
+                Who::Owner => "your".to_string(),
+                who => format!("{}'s", object(who)),
+            };
+            format!("{verb} {whose} {}.", modifier_label(kind))
+        }
+        EffectTemplate::EnergyGain { amount } => {
+            let before = before(|p| match p {
+                EffectTemplate::EnergyGain { amount } => Some(*amount as i32),
+                _ => None,
+            });
+            format!("Gain {} Energy.", number(amount as i32, before))
+        }
+        EffectTemplate::EnergyLoss { amount } => {
+            let before = before(|p| match p {
+                EffectTemplate::EnergyLoss { amount } => Some(*amount as i32),
+                _ => None,
+            });
+            format!("Lose {} Energy.", number(amount as i32, before))
+        }
+        EffectTemplate::AddShivs { count } => {
+            let before = before(|p| match p {
+                EffectTemplate::AddShivs { count } => Some(*count as i32),
+                _ => None,
+            });
+            format!("Add {} {} into your hand.", number(count as i32, before), plural(count, "Shiv"))
+        }
+        EffectTemplate::CardDraw { count } => {
+            let before = before(|p| match p {
+                EffectTemplate::CardDraw { count } => Some(*count as i32),
+                _ => None,
+            });
+            format!("Draw {} {}.", number(count as i32, before), plural(count, "card"))
+        }
+        EffectTemplate::CardDiscard { count, selection } => {
+            let before = before(|p| match p {
+                EffectTemplate::CardDiscard { count, .. } => Some(*count as i32),
+                _ => None,
+            });
+            let random = if selection == SelectionKind::Random { " at random" } else { "" };
+            format!("Discard {} {}{random}.", number(count as i32, before), plural(count, "card"))
+        }
+        EffectTemplate::CardExhaustRandom { count } => {
+            let before = before(|p| match p {
+                EffectTemplate::CardExhaustRandom { count } => Some(*count as i32),
+                _ => None,
+            });
+            format!("Exhaust {} random {} in your hand.", number(count as i32, before), plural(count, "card"))
+        }
+        EffectTemplate::CardFetchRandom { pile } => {
+            format!("Put a random card from your {} into your hand.", pile_label(pile))
+        }
+        EffectTemplate::CardAdd { name, count, pile } => {
+            let before = before(|p| match p {
+                EffectTemplate::CardAdd { count, .. } => Some(*count as i32),
+                _ => None,
+            });
+            format!("Shuffle {} {} into your {}.", number(count as i32, before), name.as_str(), pile_label(pile))
+        }
+        EffectTemplate::CardUpgradeAll { name } => format!("Upgrade all {} cards.", name.as_str()),
+        EffectTemplate::CalculatedGamble => "Discard your hand, then draw that many cards.".to_string(),
+        EffectTemplate::If { condition, then } => {
+            let prev = prev.and_then(|(p, _)| match p {
+                EffectTemplate::If { then, .. } => Some(*then),
+                _ => None,
+            });
+            format!("If {}, {}.", condition_text(condition), clause(effects_text(then, prev, ctx)))
+        }
+        EffectTemplate::ForEach { count, each } => {
+            let prev = prev.and_then(|(p, _)| match p {
+                EffectTemplate::ForEach { each, .. } => Some(*each),
+                _ => None,
+            });
+            let hits = match (ctx.state, ctx.card) {
+                (Some(state), Some(_)) => {
+                    let hits = count_value(state, count);
+                    format!(" (Hits {hits} {}.)", if hits == 1 { "time" } else { "times" })
+                }
+                _ => String::new(),
+            };
+            let each = clause(effects_text(each, prev, ctx));
+            let each = capitalized(&each);
+            format!("{each} {}.{hits}", count_text(count))
+        }
+        EffectTemplate::Scaled { count, bonus, each } => {
+            let prev = prev.and_then(|(p, _)| match p {
+                EffectTemplate::Scaled { each, .. } => Some(*each),
+                _ => None,
+            });
+            let x = match bonus {
+                0 => "X".to_string(),
+                b => format!("X+{b}"),
+            };
+            let sentences: Vec<String> = effects_text(each, prev, ctx)
+                .iter()
+                .map(|s| match count {
+                    // The templates hold the amount per point of X
+                    CountKind::XCost => s.replacen(" 1 ", &format!(" {x} "), 1),
+                    _ => format!("{} {}.", s.trim_end_matches('.'), count_text(count)),
+                })
+                .collect();
+            sentences.join(" ")
+        }
+        EffectTemplate::PlayableIf { condition } => {
+            format!("Can only be played if {}.", condition_text(condition))
+        }
+        EffectTemplate::MonsterSummon { pool, count } => match pool {
+            [name] => format!("Summon {count} {}.", name.as_str()),
+            _ => format!("Summon {count} {}.", plural(count, "minion")),
+        },
+        EffectTemplate::MonsterSplit { into } => {
+            let names: Vec<_> = into.iter().map(|m| m.as_str()).collect();
+            format!("Split into {}.", names.join(" and "))
+        }
+        EffectTemplate::Suicide => "Die.".to_string(),
+        EffectTemplate::GoldSteal { amount } => format!("Steal {amount} Gold."),
+        EffectTemplate::Escape => "Escape.".to_string(),
+        EffectTemplate::Recover { percent } => format!("Remove all debuffs and heal to {percent}% HP."),
+    }
+}
+
+/// Sentences joined into one clause: "gain 1 Energy and draw 1 card".
+fn clause(sentences: Vec<String>) -> String {
+    let parts: Vec<String> = sentences
+        .iter()
+        .map(|s| {
+            let s = s.trim_end_matches('.');
+            let mut chars = s.chars();
+            chars.next().map(|c| c.to_lowercase().chain(chars).collect()).unwrap_or_default()
+        })
+        .collect();
+    parts.join(" and ")
+}
+
+fn capitalized(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+fn condition_text(condition: Condition) -> String {
+    match condition {
+        Condition::TargetHas { kind: ModifierKind::Poison } => "the enemy is Poisoned".to_string(),
+        Condition::TargetHas {
+            kind: kind @ (ModifierKind::Weak | ModifierKind::Vulnerable | ModifierKind::Frail),
+        } => format!("the enemy is {}", modifier_label(kind)),
+        Condition::TargetHas { kind } => format!("the enemy has {}", modifier_label(kind)),
+        Condition::DiscardedThisTurn => "you have discarded a card this turn".to_string(),
+        Condition::DrawPileEmpty => "there are no cards in your draw pile".to_string(),
+    }
+}
+
+fn count_text(count: CountKind) -> &'static str {
+    match count {
+        CountKind::AttacksPlayedThisTurn => "for each Attack played this turn",
+        CountKind::SkillsInHand => "for each Skill in your hand",
+        CountKind::CardsInHand => "for each card in your hand",
+        CountKind::XCost => "X times",
+    }
+}
+
+fn live_damage(base: u16, ctx: Context) -> i32 {
+    match ctx.state {
+        Some(state) => damage_attack_outgoing(state, ctx.owner, base, ctx.card) as i32,
+        None => base as i32,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Wording helpers
+// ---------------------------------------------------------------------------
+
+/// The value, followed by its delta when it differs from `before`.
+fn number(value: i32, before: Option<i32>) -> String {
+    match before {
+        Some(b) if b != value => format!("{value} ({:+})", value - b),
+        _ => value.to_string(),
+    }
+}
+
+fn to(who: Who) -> &'static str {
+    match who {
+        Who::Owner | Who::Enemy => "",
+        Who::AllEnemies => " to ALL enemies",
+        Who::RandomEnemy => " to a random enemy",
+        Who::AllAllies => " to ALL allies",
+        Who::RandomAlly => " to a random ally",
+    }
+}
+
+fn object(who: Who) -> &'static str {
+    match who {
+        Who::Owner => "yourself",
+        Who::Enemy => "the enemy",
+        Who::AllEnemies => "ALL enemies",
+        Who::RandomEnemy => "a random enemy",
+        Who::AllAllies => "ALL allies",
+        Who::RandomAlly => "a random ally",
+    }
+}
+
+fn subject_loses(who: Who) -> &'static str {
+    match who {
+        Who::Owner => "Lose",
+        Who::Enemy => "The enemy loses",
+        Who::AllEnemies => "ALL enemies lose",
+        Who::RandomEnemy => "A random enemy loses",
+        Who::AllAllies => "ALL allies lose",
+        Who::RandomAlly => "A random ally loses",
+    }
+}
+
+fn pile_label(pile: PileKind) -> &'static str {
+    match pile {
+        PileKind::Draw => "draw pile",
+        PileKind::Discard => "discard pile",
+    }
+}
+
+fn plural(count: u8, noun: &str) -> String {
+    if count == 1 { noun.to_string() } else { format!("{noun}s") }
+}
+
+/// "DoubleDamage" -> "Double Damage"
+fn modifier_label(kind: ModifierKind) -> String {
+    let name = format!("{kind:?}");
+    let mut label = String::with_capacity(name.len() + 4);
+    let mut prev = ' ';
+    for c in name.chars() {
+        if c.is_uppercase() && prev.is_lowercase() {
+            label.push(' ');
+        }
+        label.push(c);
+        prev = c;
+    }
+    label
+}
@@ -1,73 +1,166 @@
 // Core type enums shared across the engine.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(u8)]
-pub enum CardName {
-    AThousandCuts,
-    Accuracy,
-    Acrobatics,
-    Adrenaline,
-    AfterImage,
-    AllOutAttack,
-    Backflip,
-    Backstab,
-    BladeDance,
-    Blur,
-    Burst,
-    CalculatedGamble,
-    CloakAndDagger,
-    DaggerThrow,
-    Dash,
-    Defend,
-    Deflect,
-    DieDieDie,
-    DodgeAndRoll,
-    FlyingKnee,
-    Footwork,
-    InfiniteBlades,
-    LegSweep,
-    Neutralize,
-    Outmaneuver,
-    PhantasmalKiller,
-    Shiv,
-    Strike,
-    Survivor,
-    Terror,
+use std::fmt;
+
+use crate::registry;
+
+/// ID of a card: the built-in ones below, then those registered at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardName(pub u16);
+
+#[allow(non_upper_case_globals)]
+impl CardName {
+    pub const AThousandCuts: Self = Self(0);
+    pub const Accuracy: Self = Self(1);
+    pub const Acrobatics: Self = Self(2);
+    pub const Adrenaline: Self = Self(3);
+    pub const AfterImage: Self = Self(4);
+    pub const AllOutAttack: Self = Self(5);
+    pub const AscendersBane: Self = Self(6);
+    pub const Backflip: Self = Self(7);
+    pub const Backstab: Self = Self(8);
+    pub const Bane: Self = Self(9);
+    pub const BladeDance: Self = Self(10);
+    pub const Blur: Self = Self(11);
+    pub const BouncingFlask: Self = Self(12);
+    pub const Burn: Self = Self(13);
+    pub const Burst: Self = Self(14);
+    pub const CalculatedGamble: Self = Self(15);
+    pub const Catalyst: Self = Self(16);
+    pub const CloakAndDagger: Self = Self(17);
+    pub const Clumsy: Self = Self(18);
+    pub const CorpseExplosion: Self = Self(19);
+    pub const CripplingCloud: Self = Self(20);
+    pub const DaggerThrow: Self = Self(21);
+    pub const Dash: Self = Self(22);
+    pub const Dazed: Self = Self(23);
+    pub const DeadlyPoison: Self = Self(24);
+    pub const Defend: Self = Self(25);
+    pub const Deflect: Self = Self(26);
+    pub const DieDieDie: Self = Self(27);
+    pub const DodgeAndRoll: Self = Self(28);
+    pub const Doubt: Self = Self(29);
+    pub const Envenom: Self = Self(30);
+    pub const FlyingKnee: Self = Self(31);
+    pub const Footwork: Self = Self(32);
+    pub const InfiniteBlades: Self = Self(33);
+    pub const Injury: Self = Self(34);
+    pub const LegSweep: Self = Self(35);
+    pub const Neutralize: Self = Self(36);
+    pub const NoxiousFumes: Self = Self(37);
+    pub const Outmaneuver: Self = Self(38);
+    pub const Parasite: Self = Self(39);
+    pub const PhantasmalKiller: Self = Self(40);
+    pub const PoisonedStab: Self = Self(41);
+    pub const Regret: Self = Self(42);
+    pub const Shiv: Self = Self(43);
+    pub const Slimed: Self = Self(44);
+    pub const Strike: Self = Self(45);
+    pub const Survivor: Self = Self(46);
+    pub const Terror: Self = Self(47);
+    pub const Void: Self = Self(48);
+    pub const Wound: Self = Self(49);
+    pub const Finisher: Self = Self(50);
+    pub const Flechettes: Self = Self(51);
+    pub const GrandFinale: Self = Self(52);
+    pub const HeelHook: Self = Self(53);
+    pub const SneakyStrike: Self = Self(54);
+    pub const Doppelganger: Self = Self(55);
+    pub const Malaise: Self = Self(56);
+    pub const Skewer: Self = Self(57);
 }
 
+pub const CARD_NAMES_BUILTIN: usize = 58;
+
+/// (identifier, display name) of the built-ins, by ID.
+static CARD_NAMES: [(&str, &str); CARD_NAMES_BUILTIN] = [
+    ("AThousandCuts", "A Thousand Cuts"),
+    ("Accuracy", "Accuracy"),
+    ("Acrobatics", "Acrobatics"),
+    ("Adrenaline", "Adrenaline"),
+    ("AfterImage", "After Image"),
+    ("AllOutAttack", "All Out Attack"),
+    ("AscendersBane", "Ascender's Bane"),
+    ("Backflip", "Backflip"),
+    ("Backstab", "Backstab"),
+    ("Bane", "Bane"),
+    ("BladeDance", "Blade Dance"),
+    ("Blur", "Blur"),
+    ("BouncingFlask", "Bouncing Flask"),
+    ("Burn", "Burn"),
+    ("Burst", "Burst"),
+    ("CalculatedGamble", "Calculated Gamble"),
+    ("Catalyst", "Catalyst"),
+    ("CloakAndDagger", "Cloak And Dagger"),
+    ("Clumsy", "Clumsy"),
+    ("CorpseExplosion", "Corpse Explosion"),
+    ("CripplingCloud", "Crippling Cloud"),
+    ("DaggerThrow", "Dagger Throw"),
+    ("Dash", "Dash"),
+    ("Dazed", "Dazed"),
+    ("DeadlyPoison", "Deadly Poison"),
+    ("Defend", "Defend"),
+    ("Deflect", "Deflect"),
+    ("DieDieDie", "Die Die Die"),
+    ("DodgeAndRoll", "Dodge And Roll"),
+    ("Doubt", "Doubt"),
+    ("Envenom", "Envenom"),
+    ("FlyingKnee", "Flying Knee"),
+    ("Footwork", "Footwork"),
+    ("InfiniteBlades", "Infinite Blades"),
+    ("Injury", "Injury"),
+    ("LegSweep", "Leg Sweep"),
+    ("Neutralize", "Neutralize"),
+    ("NoxiousFumes", "Noxious Fumes"),
+    ("Outmaneuver", "Outmaneuver"),
+    ("Parasite", "Parasite"),
+    ("PhantasmalKiller", "Phantasmal Killer"),
+    ("PoisonedStab", "Poisoned Stab"),
+    ("Regret", "Regret"),
+    ("Shiv", "Shiv"),
+    ("Slimed", "Slimed"),
+    ("Strike", "Strike"),
+    ("Survivor", "Survivor"),
+    ("Terror", "Terror"),
+    ("Void", "Void"),
+    ("Wound", "Wound"),
+    ("Finisher", "Finisher"),
+    ("Flechettes", "Flechettes"),
+    ("GrandFinale", "Grand Finale"),
+    ("HeelHook", "Heel Hook"),
+    ("SneakyStrike", "Sneaky Strike"),
+    ("Doppelganger", "Doppelganger"),
+    ("Malaise", "Malaise"),
+    ("Skewer", "Skewer"),
+];
+
 impl CardName {
+    /// Matches the identifier printed by `Debug`; also finds registered content.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match CARD_NAMES.iter().position(|(ident, _)| *ident == name) {
+            Some(i) => Some(Self(i as u16)),
+            None => registry::card_find(name),
+        }
+    }
+
+    pub fn is_builtin(self) -> bool {
+        (self.0 as usize) < CARD_NAMES_BUILTIN
+    }
+
     pub fn as_str(self) -> &'static str {
-        match self {
-            Self::AThousandCuts => "A Thousand Cuts",
-            Self::Accuracy => "Accuracy",
-            Self::Acrobatics => "Acrobatics",
-            Self::Adrenaline => "Adrenaline",
-            Self::AfterImage => "After Image",
-            Self::AllOutAttack => "All Out Attack",
-            Self::Backflip => "Backflip",
-            Self::Backstab => "Backstab",
-            Self::BladeDance => "Blade Dance",
-            Self::Blur => "Blur",
-            Self::Burst => "Burst",
-            Self::CalculatedGamble => "Calculated Gamble",
-            Self::CloakAndDagger => "Cloak And Dagger",
-            Self::DaggerThrow => "Dagger Throw",
-            Self::Dash => "Dash",
-            Self::Defend => "Defend",
-            Self::Deflect => "Deflect",
-            Self::DieDieDie => "Die Die Die",
-            Self::DodgeAndRoll => "Dodge And Roll",
-            Self::FlyingKnee => "Flying Knee",
-            Self::Footwork => "Footwork",
-            Self::InfiniteBlades => "Infinite Blades",
-            Self::LegSweep => "Leg Sweep",
-            Self::Neutralize => "Neutralize",
-            Self::Outmaneuver => "Outmaneuver",
-            Self::PhantasmalKiller => "Phantasmal Killer",
-            Self::Shiv => "Shiv",
-            Self::Strike => "Strike",
-            Self::Survivor => "Survivor",
-            Self::Terror => "Terror",
+        match CARD_NAMES.get(self.0 as usize) {
+            Some((_, name)) => name,
+            None => registry::card_name(self),
+        }
+    }
+}
+
+/// Built-ins print as their identifier, registered content as its name.
+impl fmt::Debug for CardName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match CARD_NAMES.get(self.0 as usize) {
+            Some((ident, _)) => f.write_str(ident),
+            None => f.write_str(self.as_str()),
         }
     }
 }
@@ -99,24 +192,187 @@ pub enum CardRarity {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum MonsterName {
-    Cultist,
-    Dummy,
-    FungiBeast,
-    JawWorm,
-    LouseGreen,
-    TheGuardian,
+pub enum RelicName {
+    Anchor,
+    BagOfMarbles,
+    BustedCrown,
+    ChemicalX,
+    Ectoplasm,
+    Mango,
+    Pear,
+    PhilosophersStone,
+    Vajra,
 }
 
-impl MonsterName {
+impl RelicName {
     pub fn as_str(self) -> &'static str {
         match self {
-            Self::Cultist => "Cultist",
-            Self::Dummy => "Dummy",
-            Self::FungiBeast => "Fungi Beast",
-            Self::JawWorm => "Jaw Worm",
-            Self::LouseGreen => "Louse (green)",
-            Self::TheGuardian => "The Guardian",
+            Self::Anchor => "Anchor",
+            Self::BagOfMarbles => "Bag of Marbles",
+            Self::BustedCrown => "Busted Crown",
+            Self::ChemicalX => "Chemical X",
+            Self::Ectoplasm => "Ectoplasm",
+            Self::Mango => "Mango",
+            Self::Pear => "Pear",
+            Self::PhilosophersStone => "Philosopher's Stone",
+            Self::Vajra => "Vajra",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelicRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Boss,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChestSize {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyKind {
+    Emerald,
+    Ruby,
+    Sapphire,
+}
+
+/// ID of a monster: the built-in ones below, then those registered at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MonsterName(pub u16);
+
+#[allow(non_upper_case_globals)]
+impl MonsterName {
+    pub const AcidSlimeL: Self = Self(0);
+    pub const AcidSlimeM: Self = Self(1);
+    pub const AcidSlimeS: Self = Self(2);
+    pub const BlueSlaver: Self = Self(3);
+    pub const Byrd: Self = Self(4);
+    pub const Chosen: Self = Self(5);
+    pub const CorruptHeart: Self = Self(6);
+    pub const Cultist: Self = Self(7);
+    pub const Dagger: Self = Self(8);
+    pub const Deca: Self = Self(9);
+    pub const Donu: Self = Self(10);
+    pub const Dummy: Self = Self(11);
+    pub const FatGremlin: Self = Self(12);
+    pub const FungiBeast: Self = Self(13);
+    pub const GiantHead: Self = Self(14);
+    pub const GremlinLeader: Self = Self(15);
+    pub const GremlinNob: Self = Self(16);
+    pub const GremlinWizard: Self = Self(17);
+    pub const Hexaghost: Self = Self(18);
+    pub const JawWorm: Self = Self(19);
+    pub const Looter: Self = Self(20);
+    pub const LouseGreen: Self = Self(21);
+    pub const LouseRed: Self = Self(22);
+    pub const MadGremlin: Self = Self(23);
+    pub const OrbWalker: Self = Self(24);
+    pub const RedSlaver: Self = Self(25);
+    pub const Reptomancer: Self = Self(26);
+    pub const ShieldGremlin: Self = Self(27);
+    pub const SlimeBoss: Self = Self(28);
+    pub const SneakyGremlin: Self = Self(29);
+    pub const SpikeSlimeL: Self = Self(30);
+    pub const SpikeSlimeM: Self = Self(31);
+    pub const SpikeSlimeS: Self = Self(32);
+    pub const SpireShield: Self = Self(33);
+    pub const SpireSpear: Self = Self(34);
+    pub const Taskmaster: Self = Self(35);
+    pub const TheChamp: Self = Self(36);
+    pub const TheGuardian: Self = Self(37);
+    pub const WrithingMass: Self = Self(38);
+    pub const Exploder: Self = Self(39);
+    pub const Mugger: Self = Self(40);
+    pub const Repulsor: Self = Self(41);
+    pub const Spiker: Self = Self(42);
+    pub const AwakenedOne: Self = Self(43);
+    pub const TimeEater: Self = Self(44);
+}
+
+pub const MONSTER_NAMES_BUILTIN: usize = 45;
+
+/// (identifier, display name) of the built-ins, by ID.
+static MONSTER_NAMES: [(&str, &str); MONSTER_NAMES_BUILTIN] = [
+    ("AcidSlimeL", "Acid Slime (L)"),
+    ("AcidSlimeM", "Acid Slime (M)"),
+    ("AcidSlimeS", "Acid Slime (S)"),
+    ("BlueSlaver", "Blue Slaver"),
+    ("Byrd", "Byrd"),
+    ("Chosen", "Chosen"),
+    ("CorruptHeart", "Corrupt Heart"),
+    ("Cultist", "Cultist"),
+    ("Dagger", "Dagger"),
+    ("Deca", "Deca"),
+    ("Donu", "Donu"),
+    ("Dummy", "Dummy"),
+    ("FatGremlin", "Fat Gremlin"),
+    ("FungiBeast", "Fungi Beast"),
+    ("GiantHead", "Giant Head"),
+    ("GremlinLeader", "Gremlin Leader"),
+    ("GremlinNob", "Gremlin Nob"),
+    ("GremlinWizard", "Gremlin Wizard"),
+    ("Hexaghost", "Hexaghost"),
+    ("JawWorm", "Jaw Worm"),
+    ("Looter", "Looter"),
+    ("LouseGreen", "Louse (green)"),
+    ("LouseRed", "Louse (red)"),
+    ("MadGremlin", "Mad Gremlin"),
+    ("OrbWalker", "Orb Walker"),
+    ("RedSlaver", "Red Slaver"),
+    ("Reptomancer", "Reptomancer"),
+    ("ShieldGremlin", "Shield Gremlin"),
+    ("SlimeBoss", "Slime Boss"),
+    ("SneakyGremlin", "Sneaky Gremlin"),
+    ("SpikeSlimeL", "Spike Slime (L)"),
+    ("SpikeSlimeM", "Spike Slime (M)"),
+    ("SpikeSlimeS", "Spike Slime (S)"),
+    ("SpireShield", "Spire Shield"),
+    ("SpireSpear", "Spire Spear"),
+    ("Taskmaster", "Taskmaster"),
+    ("TheChamp", "The Champ"),
+    ("TheGuardian", "The Guardian"),
+    ("WrithingMass", "Writhing Mass"),
+    ("Exploder", "Exploder"),
+    ("Mugger", "Mugger"),
+    ("Repulsor", "Repulsor"),
+    ("Spiker", "Spiker"),
+    ("AwakenedOne", "Awakened One"),
+    ("TimeEater", "Time Eater"),
+];
+
+impl MonsterName {
+    /// Matches the identifier printed by `Debug`; also finds registered content.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match MONSTER_NAMES.iter().position(|(ident, _)| *ident == name) {
+            Some(i) => Some(Self(i as u16)),
+            None => registry::monster_find(name),
+        }
+    }
+
+    pub fn is_builtin(self) -> bool {
+        (self.0 as usize) < MONSTER_NAMES_BUILTIN
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match MONSTER_NAMES.get(self.0 as usize) {
+            Some((_, name)) => name,
+            None => registry::monster_name(self),
+        }
+    }
+}
+
+/// Built-ins print as their identifier, registered content as its name.
+impl fmt::Debug for MonsterName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match MONSTER_NAMES.get(self.0 as usize) {
+            Some((ident, _)) => f.write_str(ident),
+            None => f.write_str(self.as_str()),
         }
     }
 }
@@ -131,8 +387,10 @@ pub enum MonsterKind {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RoomType {
     CombatBoss,
+    CombatElite,
     CombatMonster,
     RestSite,
+    Treasure,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -145,6 +403,7 @@ pub enum Fsm {
     GameOver,
     Map,
     RestSite,
+    RelicReward,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -2,10 +2,13 @@
 
 use pyo3::prelude::*;
 
-use crate::effect::EffectTemplate;
-use crate::modifier::{ModifierKind, modifier_has, modifier_stacks};
-use crate::process::FACTOR_VULN;
-use crate::state::GameState;
+use crate::effect::{Condition, EffectTemplate, TargetKind};
+use crate::game::CardPreview;
+use crate::process::damage_attack_amount;
+use crate::relic::relic_rarity;
+use crate::state::{GameState, IntentKind, Move, TurnRecord};
+use crate::text::{card_text, card_text_upgrade, move_text};
+use crate::types::{ActorId, RelicName};
 
 // ---------------------------------------------------------------------------
 // View types (PyO3 classes)
@@ -17,6 +20,7 @@ pub struct ViewEffectTemplate {
     pub effect_type: String,
     pub value: Option<i32>,
     pub target: Option<String>,
+    pub condition: Option<String>, // set on the templates inside If, ForEach and Scaled
 }
 
 #[pyclass(frozen, get_all)]
@@ -27,13 +31,21 @@ pub struct ViewCard {
     pub color: String,
     pub rarity: String,
     pub cost: u8,
+    pub x_cost: bool,
     pub upgraded: bool,
     pub exhaust: bool,
     pub innate: bool,
+    pub ethereal: bool,
+    pub unplayable: bool,
+    pub playable: bool, // right now, for cards in combat piles
     pub is_active: bool,
     pub requires_target: bool,
     pub requires_discard: bool,
     pub effects: Vec<ViewEffectTemplate>,
+    pub end_of_turn: Vec<ViewEffectTemplate>,
+    pub on_draw: Vec<ViewEffectTemplate>,
+    pub text: String,
+    pub text_upgrade: Option<String>,
 }
 
 #[pyclass(frozen, get_all)]
@@ -43,6 +55,13 @@ pub struct ViewModifier {
     pub stacks: i16,
 }
 
+#[pyclass(frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct ViewRelic {
+    pub name: String,
+    pub rarity: String,
+}
+
 #[pyclass(frozen, get_all)]
 #[derive(Debug, Clone)]
 pub struct ViewCharacter {
@@ -59,6 +78,7 @@ pub struct ViewCharacter {
 pub struct ViewIntent {
     pub damage: Option<u16>,
     pub instances: Option<u8>,
+    pub kind: String,
     pub block: bool,
     pub buff: bool,
     pub debuff: bool,
@@ -73,6 +93,9 @@ pub struct ViewMonster {
     pub block_current: u16,
     pub modifiers: Vec<ViewModifier>,
     pub intent: ViewIntent,
+    pub move_name: Option<String>,
+    pub move_text: Option<String>,
+    pub alive: bool,
 }
 
 #[pyclass(frozen, get_all)]
@@ -87,6 +110,7 @@ pub struct ViewEnergy {
 pub struct ViewMapNode {
     pub room_type: String,
     pub x_next: Vec<usize>,
+    pub burning: bool,
 }
 
 #[pyclass(frozen, get_all)]
@@ -95,6 +119,33 @@ pub struct ViewMap {
     pub nodes: Vec<Vec<Option<ViewMapNode>>>,
     pub y_current: Option<usize>,
     pub x_current: Option<usize>,
+    pub boss: Vec<String>,
+    pub boss_second: Option<Vec<String>>,
+}
+
+/// One turn of `GameState.combat_history`, or the whole combat summed up.
+#[pyclass(frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct ViewTurn {
+    pub attacks_played: u16,
+    pub skills_played: u16,
+    pub powers_played: u16,
+    pub cards_played: u16, // all kinds
+    pub cards_discarded: u16,
+    pub damage_dealt: u16,
+    pub damage_taken: u16,
+}
+
+/// What playing a card would do, per monster in `monsters` order.
+#[pyclass(frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct ViewCardPreview {
+    pub damage: Vec<u16>, // health and block lost
+    pub health_lost: Vec<u16>,
+    pub block: u16,
+    pub energy: i16,
+    pub cards_drawn: u8,
+    pub fsm: String, // after the play, e.g. CombatAwaitDiscard
 }
 
 #[pyclass(frozen, get_all)]
@@ -102,14 +153,25 @@ pub struct ViewMap {
 pub struct ViewGameState {
     pub character: ViewCharacter,
     pub monsters: Vec<ViewMonster>,
+    pub incoming_damage: u16,
+    pub turn: u16,
+    pub turn_history: Vec<ViewTurn>, // the last one is this turn
+    pub combat_totals: ViewTurn,
     pub deck: Vec<ViewCard>,
     pub hand: Vec<ViewCard>,
     pub pile_draw: Vec<ViewCard>,
     pub pile_disc: Vec<ViewCard>,
     pub pile_exhaust: Vec<ViewCard>,
     pub reward_combat: Vec<ViewCard>,
+    pub reward_relic: Vec<ViewRelic>,
+    pub relics: Vec<ViewRelic>,
+    pub gold: u16,
+    pub key_ruby: bool,
+    pub key_emerald: bool,
+    pub key_sapphire: bool,
     pub energy: ViewEnergy,
     pub map: ViewMap,
+    pub act: u8,
     pub fsm: String,
 }
 
@@ -118,51 +180,89 @@ pub struct ViewGameState {
 // ---------------------------------------------------------------------------
 
 pub fn build_view(state: &GameState) -> ViewGameState {
+    let monsters = build_view_monsters(state);
     ViewGameState {
         character: build_view_character(state),
-        monsters: build_view_monsters(state),
+        incoming_damage: incoming_damage(&monsters),
+        monsters,
+        turn: state.combat_history.turn(),
+        turn_history: state.combat_history.turns.iter().map(build_view_turn).collect(),
+        combat_totals: build_view_turn(&state.combat_history.combat()),
         deck: state
             .deck
             .iter()
-            .map(|c| build_view_card(c, false))
+            .map(|c| build_view_card(c, false, None))
             .collect(),
         hand: state
             .hand
             .iter()
             .map(|&i| {
                 let is_active = state.card_active == Some(i);
-                build_view_card(&state.combat_cards[i], is_active)
+                build_view_card(&state.combat_cards[i], is_active, Some(state))
             })
             .collect(),
         pile_draw: state
             .draw_pile
             .iter()
-            .map(|&i| build_view_card(&state.combat_cards[i], false))
+            .map(|&i| build_view_card(&state.combat_cards[i], false, Some(state)))
             .collect(),
         pile_disc: state
             .discard_pile
             .iter()
-            .map(|&i| build_view_card(&state.combat_cards[i], false))
+            .map(|&i| build_view_card(&state.combat_cards[i], false, Some(state)))
             .collect(),
         pile_exhaust: state
             .exhaust_pile
             .iter()
-            .map(|&i| build_view_card(&state.combat_cards[i], false))
+            .map(|&i| build_view_card(&state.combat_cards[i], false, Some(state)))
             .collect(),
         reward_combat: state
             .card_rewards
             .iter()
-            .map(|c| build_view_card(c, false))
+            .map(|c| build_view_card(c, false, None))
             .collect(),
+        reward_relic: state.relic_rewards.iter().map(|&r| build_view_relic(r)).collect(),
+        relics: state.relics.iter().map(|&r| build_view_relic(r)).collect(),
+        gold: state.gold,
+        key_ruby: state.keys.ruby,
+        key_emerald: state.keys.emerald,
+        key_sapphire: state.keys.sapphire,
         energy: ViewEnergy {
             current: state.energy.current,
             max: state.energy.max,
         },
         map: build_view_map(state),
+        act: state.act,
         fsm: format!("{:?}", state.fsm),
     }
 }
 
+pub fn build_view_card_preview(before: &GameState, preview: &CardPreview) -> ViewCardPreview {
+    let after = &preview.state;
+    let health_lost: Vec<u16> = before
+        .monsters
+        .iter()
+        .zip(&after.monsters)
+        .map(|(b, a)| b.vitals.health.saturating_sub(a.vitals.health))
+        .collect();
+    let damage = before
+        .monsters
+        .iter()
+        .zip(&after.monsters)
+        .zip(&health_lost)
+        .map(|((b, a), lost)| lost + b.vitals.block.saturating_sub(a.vitals.block))
+        .collect();
+
+    ViewCardPreview {
+        damage,
+        health_lost,
+        block: after.character.vitals.block.saturating_sub(before.character.vitals.block),
+        energy: after.energy.current as i16 - before.energy.current as i16,
+        cards_drawn: preview.cards_drawn,
+        fsm: format!("{:?}", after.fsm),
+    }
+}
+
 fn build_view_character(state: &GameState) -> ViewCharacter {
     let c = &state.character;
     ViewCharacter {
@@ -175,41 +275,39 @@ fn build_view_character(state: &GameState) -> ViewCharacter {
     }
 }
 
+fn build_view_turn(turn: &TurnRecord) -> ViewTurn {
+    ViewTurn {
+        attacks_played: turn.cards_played.attack,
+        skills_played: turn.cards_played.skill,
+        powers_played: turn.cards_played.power,
+        cards_played: turn.cards_played.total(),
+        cards_discarded: turn.cards_discarded,
+        damage_dealt: turn.damage_dealt,
+        damage_taken: turn.damage_taken,
+    }
+}
+
 fn build_view_monsters(state: &GameState) -> Vec<ViewMonster> {
     state
         .monsters
         .iter()
-        .map(|m| {
-            let intent = if let Some(move_idx) = m.move_current {
-                let mv = &m.moves[move_idx];
-                let mut damage = mv.intent.damage;
-
-                // Correct intent damage with modifiers
-                if let Some(d) = damage {
-                    let mut dmg = d as f32;
-                    if modifier_has(&m.vitals.modifiers, ModifierKind::Strength) {
-                        dmg += modifier_stacks(&m.vitals.modifiers, ModifierKind::Strength) as f32;
-                    }
-                    if modifier_has(&m.vitals.modifiers, ModifierKind::Weak) {
-                        dmg *= 0.75;
-                    }
-                    if modifier_has(&state.character.vitals.modifiers, ModifierKind::Vulnerable) {
-                        dmg *= FACTOR_VULN;
-                    }
-                    damage = Some(dmg as u16);
-                }
-
+        .enumerate()
+        .map(|(i, m)| {
+            let shown = m.move_current.filter(|_| m.alive).map(|idx| &m.moves[idx]);
+            let intent = if let Some(mv) = shown {
                 ViewIntent {
-                    damage,
+                    damage: intent_damage(state, i as u8, mv),
                     instances: mv.intent.instances,
-                    block: mv.intent.block,
-                    buff: mv.intent.buff,
-                    debuff: mv.intent.debuff,
+                    kind: format!("{:?}", mv.intent.kind),
+                    block: mv.intent.kind.block(),
+                    buff: mv.intent.kind.buff(),
+                    debuff: mv.intent.kind.debuff(),
                 }
             } else {
                 ViewIntent {
                     damage: None,
                     instances: None,
+                    kind: format!("{:?}", IntentKind::Unknown),
                     block: false,
                     buff: false,
                     debuff: false,
@@ -223,29 +321,56 @@ fn build_view_monsters(state: &GameState) -> Vec<ViewMonster> {
                 block_current: m.vitals.block,
                 modifiers: build_view_modifiers(&m.vitals.modifiers),
                 intent,
+                move_name: shown.map(|mv| mv.name.to_string()),
+                move_text: shown.map(|mv| move_text(mv, state, i as u8)),
+                alive: m.alive,
             }
         })
         .collect()
 }
 
+/// Damage per hit of the move's attack, after the same modifiers a hit would
+/// see. Health-scaled attacks (Hexaghost's Divider) read the character's health.
+fn intent_damage(state: &GameState, monster_idx: u8, mv: &Move) -> Option<u16> {
+    let base = mv.intent.damage.or_else(|| {
+        mv.effects.iter().find_map(|e| match e {
+            EffectTemplate::DamagePhysicalPerHealth { divisor, .. } => Some(state.character.vitals.health / divisor + 1),
+            _ => None,
+        })
+    })?;
+    Some(damage_attack_amount(state, ActorId::Monster(monster_idx), ActorId::Character, base, None))
+}
+
+/// Attack damage the living monsters' intents add up to this turn.
+fn incoming_damage(monsters: &[ViewMonster]) -> u16 {
+    monsters
+        .iter()
+        .filter(|m| m.alive)
+        .filter_map(|m| Some(m.intent.damage? * m.intent.instances.unwrap_or(1) as u16))
+        .sum()
+}
+
 fn build_view_modifiers(mods: &crate::modifier::Modifiers) -> Vec<ViewModifier> {
-    let mut out = Vec::new();
-    let mut bits = mods.active;
-    while bits != 0 {
-        let idx = bits.trailing_zeros() as usize;
-        bits &= bits - 1;
-        let kind = ModifierKind::from_u8(idx as u8);
-        out.push(ViewModifier {
-            kind: format!("{:?}", kind),
-            stacks: mods.stacks[idx],
-        });
+    mods.active
+        .iter()
+        .map(|m| ViewModifier {
+            kind: format!("{:?}", m.kind),
+            stacks: m.stacks,
+        })
+        .collect()
+}
+
+fn build_view_relic(name: RelicName) -> ViewRelic {
+    ViewRelic {
+        name: name.as_str().to_string(),
+        rarity: format!("{:?}", relic_rarity(name)),
     }
-    out
 }
 
 use crate::cards::Card;
 
-fn build_view_card(card: &Card, is_active: bool) -> ViewCard {
+/// Cards in combat piles show live values for the character.
+fn build_view_card(card: &Card, is_active: bool, state: Option<&GameState>) -> ViewCard {
     ViewCard {
         name: if card.upgraded {
             format!("{}+", card.name.as_str())
@@ -256,13 +381,66 @@ fn build_view_card(card: &Card, is_active: bool) -> ViewCard {
         color: format!("{:?}", card.color),
         rarity: format!("{:?}", card.rarity),
         cost: card.cost,
+        x_cost: card.x_cost,
         upgraded: card.upgraded,
         exhaust: card.exhaust,
         innate: card.innate,
+        ethereal: card.ethereal,
+        unplayable: card.unplayable,
+        playable: !card.unplayable
+            && state.is_none_or(|s| card.cost_met(s.energy.current) && card.conditions_met(s)),
         is_active,
         requires_target: card.requires_target(),
         requires_discard: card.requires_discard(),
-        effects: card.effects.iter().map(view_effect_template).collect(),
+        effects: {
+            let mut effects = vec![];
+            view_effect_templates(card.effects, None, &mut effects);
+            effects
+        },
+        end_of_turn: {
+            let mut effects = vec![];
+            view_effect_templates(card.end_of_turn, None, &mut effects);
+            effects
+        },
+        on_draw: {
+            let mut effects = vec![];
+            view_effect_templates(card.on_draw, None, &mut effects);
+            effects
+        },
+        text: card_text(card, state),
+        text_upgrade: card_text_upgrade(card),
+    }
+}
+
+/// Flattens `If`, `ForEach` and `Scaled` into their inner templates, labelled with the
+/// condition or count they depend on.
+fn view_effect_templates(effects: &[EffectTemplate], condition: Option<&str>, out: &mut Vec<ViewEffectTemplate>) {
+    for tmpl in effects {
+        match tmpl {
+            EffectTemplate::If { condition, then } => {
+                view_effect_templates(then, Some(&condition_label(*condition)), out);
+            }
+            EffectTemplate::ForEach { count, each } => {
+                view_effect_templates(each, Some(&format!("ForEach_{count:?}")), out);
+            }
+            EffectTemplate::Scaled { count, bonus: 0, each } => {
+                view_effect_templates(each, Some(&format!("Scaled_{count:?}")), out);
+            }
+            EffectTemplate::Scaled { count, bonus, each } => {
+                view_effect_templates(each, Some(&format!("Scaled_{count:?}+{bonus}")), out);
+            }
+            _ => out.push(ViewEffectTemplate {
+                condition: condition.map(str::to_string),
+                ..view_effect_template(tmpl)
+            }),
+        }
+    }
+}
+
+fn condition_label(condition: Condition) -> String {
+    match condition {
+        Condition::TargetHas { kind } => format!("TargetHas_{kind:?}"),
+        _ => format!("{condition:?}"),
     }
 }
 
@@ -272,11 +450,31 @@ fn view_effect_template(tmpl: &EffectTemplate) -> ViewEffectTemplate {
             effect_type: "DamagePhysical".to_string(),
             value: Some(*base as i32),
             target: Some(format!("{:?}", target)),
+            condition: None,
+        },
+        EffectTemplate::DamagePhysicalPerHealth { divisor, target } => ViewEffectTemplate {
+            effect_type: "DamagePhysicalPerHealth".to_string(),
+            value: Some(*divisor as i32),
+            target: Some(format!("{:?}", target)),
+            condition: None,
+        },
+        EffectTemplate::DamageDeal { amount, target } => ViewEffectTemplate {
+            effect_type: "DamageDeal".to_string(),
+            value: Some(*amount as i32),
+            target: Some(format!("{:?}", target)),
+            condition: None,
+        },
+        EffectTemplate::HealthLoss { amount, target } => ViewEffectTemplate {
+            effect_type: "HealthLoss".to_string(),
+            value: Some(*amount as i32),
+            target: Some(format!("{:?}", target)),
+            condition: None,
         },
         EffectTemplate::BlockGain { amount, target } => ViewEffectTemplate {
             effect_type: "BlockGain".to_string(),
             value: Some(*amount as i32),
             target: Some(format!("{:?}", target)),
+            condition: None,
         },
         EffectTemplate::ModifierGain {
             kind,
@@ -286,36 +484,128 @@ fn view_effect_template(tmpl: &EffectTemplate) -> ViewEffectTemplate {
             effect_type: format!("ModifierGain_{:?}", kind),
             value: Some(*stacks as i32),
             target: Some(format!("{:?}", target)),
+            condition: None,
         },
         EffectTemplate::ModifierRemove { kind, target } => ViewEffectTemplate {
             effect_type: format!("ModifierRemove_{:?}", kind),
             value: None,
             target: Some(format!("{:?}", target)),
+            condition: None,
+        },
+        EffectTemplate::ModifierMultiply {
+            kind,
+            factor,
+            target,
+        } => ViewEffectTemplate {
+            effect_type: format!("ModifierMultiply_{:?}", kind),
+            value: Some(*factor as i32),
+            target: Some(format!("{:?}", target)),
+            condition: None,
         },
         EffectTemplate::EnergyGain { amount } => ViewEffectTemplate {
             effect_type: "EnergyGain".to_string(),
             value: Some(*amount as i32),
             target: None,
+            condition: None,
+        },
+        EffectTemplate::EnergyLoss { amount } => ViewEffectTemplate {
+            effect_type: "EnergyLoss".to_string(),
+            value: Some(*amount as i32),
+            target: None,
+            condition: None,
         },
         EffectTemplate::AddShivs { count } => ViewEffectTemplate {
             effect_type: "AddShivs".to_string(),
             value: Some(*count as i32),
             target: None,
+            condition: None,
         },
         EffectTemplate::CardDraw { count } => ViewEffectTemplate {
             effect_type: "CardDraw".to_string(),
             value: Some(*count as i32),
             target: None,
+            condition: None,
         },
-        EffectTemplate::CardDiscard { selection } => ViewEffectTemplate {
+        EffectTemplate::CardDiscard { count, selection } => ViewEffectTemplate {
             effect_type: "CardDiscard".to_string(),
-            value: None,
+            value: Some(*count as i32),
             target: Some(format!("{:?}", selection)),
+            condition: None,
+        },
+        EffectTemplate::CardExhaustRandom { count } => ViewEffectTemplate {
+            effect_type: "CardExhaustRandom".to_string(),
+            value: Some(*count as i32),
+            target: None,
+            condition: None,
+        },
+        EffectTemplate::CardFetchRandom { pile } => ViewEffectTemplate {
+            effect_type: "CardFetchRandom".to_string(),
+            value: None,
+            target: Some(format!("{:?}", pile)),
+            condition: None,
+        },
+        EffectTemplate::CardAdd { name, count, pile } => ViewEffectTemplate {
+            effect_type: format!("CardAdd_{:?}", name),
+            value: Some(*count as i32),
+            target: Some(format!("{:?}", pile)),
+            condition: None,
+        },
+        EffectTemplate::CardUpgradeAll { name } => ViewEffectTemplate {
+            effect_type: format!("CardUpgradeAll_{:?}", name),
+            value: None,
+            target: None,
+            condition: None,
         },
         EffectTemplate::CalculatedGamble => ViewEffectTemplate {
             effect_type: "CalculatedGamble".to_string(),
             value: None,
             target: None,
+            condition: None,
+        },
+        EffectTemplate::PlayableIf { condition } => ViewEffectTemplate {
+            effect_type: "PlayableIf".to_string(),
+            value: None,
+            target: None,
+            condition: Some(condition_label(*condition)),
+        },
+        EffectTemplate::If { .. } | EffectTemplate::ForEach { .. } | EffectTemplate::Scaled { .. } => {
+            unreachable!("flattened by view_effect_templates")
+        }
+        EffectTemplate::MonsterSummon { count, .. } => ViewEffectTemplate {
+            effect_type: "MonsterSummon".to_string(),
+            value: Some(*count as i32),
+            target: None,
+            condition: None,
+        },
+        EffectTemplate::MonsterSplit { into } => ViewEffectTemplate {
+            effect_type: "MonsterSplit".to_string(),
+            value: Some(into.len() as i32),
+            target: None,
+            condition: None,
+        },
+        EffectTemplate::Suicide => ViewEffectTemplate {
+            effect_type: "Suicide".to_string(),
+            value: None,
+            target: Some(format!("{:?}", TargetKind::Source)),
+            condition: None,
+        },
+        EffectTemplate::GoldSteal { amount } => ViewEffectTemplate {
+            effect_type: "GoldSteal".to_string(),
+            value: Some(*amount as i32),
+            target: Some(format!("{:?}", TargetKind::Character)),
+            condition: None,
+        },
+        EffectTemplate::Escape => ViewEffectTemplate {
+            effect_type: "Escape".to_string(),
+            value: None,
+            target: Some(format!("{:?}", TargetKind::Source)),
+            condition: None,
+        },
+        EffectTemplate::Recover { percent } => ViewEffectTemplate {
+            effect_type: "Recover".to_string(),
+            value: Some(*percent as i32),
+            target: Some(format!("{:?}", TargetKind::Source)),
+            condition: None,
         },
     }
 }
@@ -331,6 +621,7 @@ fn build_view_map(state: &GameState) -> ViewMap {
                     n.as_ref().map(|node| ViewMapNode {
                         room_type: format!("{:?}", node.room_type),
                         x_next: node.x_next.clone(),
+                        burning: node.burning,
                     })
                 })
                 .collect()
@@ -341,5 +632,10 @@ fn build_view_map(state: &GameState) -> ViewMap {
         nodes,
         y_current: state.map.active_y,
         x_current: state.map.active_x,
+        boss: state.map.boss.iter().map(|m| m.as_str().to_string()).collect(),
+        boss_second: state
+            .map
+            .boss_second
+            .map(|b| b.iter().map(|m| m.as_str().to_string()).collect()),
     }
 }